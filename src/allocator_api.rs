@@ -0,0 +1,361 @@
+/*!
+Non-empty collections that are generic over their allocator, using the `allocator-api2` crate on stable.
+
+The root `NonEmptyVec<T>` wraps `alloc::vec::Vec<T>`, which cannot take a custom allocator on stable.
+`allocator_api::NonEmptyVec<T, A>` wraps `allocator_api2::vec::Vec<T, A>` instead, and defaults to `Global`.
+Both types can be converted into each other when `A` is `Global`, without reallocating.
+
+# Examples
+
+```
+# use non_empty_continuous::NonEmptySlice;
+use non_empty_continuous::allocator_api::{Global, NonEmptyVec};
+
+let mut non_empty_vec: NonEmptyVec<i32> = NonEmptyVec::with_capacity_in(10, 3, Global);
+non_empty_vec.push(20);
+assert_eq!(non_empty_vec.try_pop(), Some(20));
+assert_eq!(non_empty_vec.try_pop(), None);
+
+let non_empty_slice: &NonEmptySlice<i32> = &non_empty_vec;
+assert_eq!(*non_empty_slice.first(), 10);
+
+let root_vec: non_empty_continuous::NonEmptyVec<i32> = non_empty_vec.into();
+```
+
+# Differences from the root `NonEmptyVec`
+
+The methods wrapping the inner vector, such as `push`, `try_remove`, `drain` or the retain variants, are shared with the root type.
+The following root APIs are not available here:
+
+- `splice`, `spare_capacity_mut`, `leak` and `from_raw_parts`;
+- the `edit`/`edit_or` guards and `extract_if`;
+- the head/tail, `split_off`, `map`, `zip`/`unzip`, `flatten`, `transpose` and top-k helpers;
+- conversions into `Box`, `Rc` and `Arc`, and the `serde` implementations.
+
+Everything implemented on `NonEmptySlice` is available through `Deref`.
+When `A` is `Global`, converting into the root `NonEmptyVec` is free, so the missing methods can be used from there:
+
+```
+use non_empty_continuous::allocator_api;
+
+let non_empty_vec: allocator_api::NonEmptyVec<i32> = allocator_api::NonEmptyVec::from_arr([1, 2, 3]);
+let root_vec: non_empty_continuous::NonEmptyVec<i32> = non_empty_vec.into();
+let (first, rest) = root_vec.into_head_tail();
+assert_eq!((first, rest), (1, vec![2, 3]));
+```
+*/
+
+use core::num::NonZeroUsize;
+use allocator_api2::{collections::TryReserveError, vec::Vec};
+
+pub use allocator_api2::alloc::{Allocator, Global};
+
+use crate::empty_error::*;
+use crate::non_empty_iter::*;
+use crate::non_empty_slice::*;
+
+/// A continuous non-empty vector, using the allocator `A`.
+///
+/// This is the allocator-aware counterpart of the root `NonEmptyVec`.
+/// Getting direct mutable acces to the inner vector is not allowed,
+/// since that way setting the size of the vector to 0 becomes possible.
+#[derive(Clone)]
+pub struct NonEmptyVec<T, A: Allocator = Global>(pub(crate) Vec<T, A>);
+
+impl<T> NonEmptyVec<T> {
+    /// Creates a new NonEmptyVec in the global allocator, with precisely one element inside of it.
+    #[inline]
+    pub fn new(item: T) -> NonEmptyVec<T> {
+        NonEmptyVec::new_in(item, Global)
+    }
+
+    /// Creates a new NonEmptyVec in the global allocator, with precisely one element inside of it, and a
+    /// stated capacity (unless `capacity` is 0, in which case the actual capacity will be 1).
+    #[inline]
+    pub fn with_capacity(item: T, capacity: usize) -> NonEmptyVec<T> {
+        NonEmptyVec::with_capacity_in(item, capacity, Global)
+    }
+
+    /// Collects `iter` into a `NonEmptyVec` in the global allocator, returning an `EmptyError` if it yields no items.
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<NonEmptyVec<T>, EmptyError<()>> {
+        let mut vec = Vec::new();
+        vec.extend(iter);
+        NonEmptyVec::try_from_vec(vec).map_err(|_| EmptyError(()))
+    }
+
+    /// Creates a `NonEmptyVec` in the global allocator from an array, whose length is checked not to be 0 at compile-time.
+    #[inline]
+    pub fn from_arr<const N: usize>(arr: [T; N]) -> NonEmptyVec<T> {
+        arr.into()
+    }
+}
+
+impl<T, A: Allocator> NonEmptyVec<T, A> {
+    /// Creates a new NonEmptyVec in the allocator `alloc`, with precisely one element inside of it.
+    #[inline]
+    pub fn new_in(item: T, alloc: A) -> NonEmptyVec<T, A> {
+        NonEmptyVec::with_capacity_in(item, 1, alloc)
+    }
+
+    /// Creates a new NonEmptyVec in the allocator `alloc`, with precisely one element inside of it, and a
+    /// stated capacity (unless `capacity` is 0, in which case the actual capacity will be 1).
+    #[inline]
+    pub fn with_capacity_in(item: T, capacity: usize, alloc: A) -> NonEmptyVec<T, A> {
+        let mut vec = Vec::with_capacity_in(capacity, alloc);
+        vec.push(item);
+        NonEmptyVec(vec)
+    }
+
+    /// Safely turns a `Vec` into a `NonEmptyVec` if the vector is not empty,
+    /// otherwise an `EmptyError` containing the original vector is returned.
+    #[inline]
+    pub fn try_from_vec(vec: Vec<T, A>) -> Result<NonEmptyVec<T, A>, EmptyError<Vec<T, A>>> {
+        if vec.is_empty() { Err(EmptyError(vec)) }
+        else { Ok(NonEmptyVec(vec)) }
+    }
+
+    /// # Safety
+    /// `vec` must not be empty.
+    #[inline]
+    pub unsafe fn from_vec_unchecked(vec: Vec<T, A>) -> NonEmptyVec<T, A> {
+        NonEmptyVec(vec)
+    }
+
+    /// Moves the inner vector out of the `NonEmptyVec`.
+    #[inline]
+    pub fn to_vec(self) -> Vec<T, A> {
+        self.0
+    }
+
+    /// Creates a consuming iterator, which is guaranteed to yield at least one item.
+    /// Consider using `into_iter` instead if the guarantee is not needed.
+    #[inline]
+    pub fn into_iter_ne(self) -> NonEmptyIter<allocator_api2::vec::IntoIter<T, A>> {
+        NonEmptyIter(self.0.into_iter())
+    }
+
+    /// Gives a read-only reference to the inner vector.
+    #[inline]
+    pub const fn get_vec(&self) -> &Vec<T, A> {
+        &self.0
+    }
+
+    // Getting a mutable reference to the inner vec is not
+    // allowed since it may be modified to become empty
+
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.0.allocator()
+    }
+
+    vec_wrapper_methods!(vec: Vec<T, A>, drain: allocator_api2::vec::Drain<'_, T, A>, reserve_error: TryReserveError);
+}
+
+impl<T, A: Allocator + Clone> NonEmptyVec<T, A> {
+    /// If `at` was 0 all items of `self` would be moved into the new vec, leaving `self` empty.
+    /// The returned vector uses a clone of `self`'s allocator.
+    #[inline]
+    pub fn split_off(&mut self, at: NonZeroUsize) -> Vec<T, A> {
+        self.0.split_off(at.get())
+    }
+}
+
+impl<T: Clone, A: Allocator> NonEmptyVec<T, A> {
+    /// Safe wrapper around `Vec::resize`, that ensures the vector cannot become empty.
+    #[inline]
+    pub fn resize(&mut self, new_len: NonZeroUsize, value: T) {
+        self.0.resize(new_len.get(), value)
+    }
+
+    /// Wrapper around `Vec::extend_from_slice`.
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.0.extend_from_slice(other)
+    }
+}
+
+impl<T: PartialEq, A: Allocator> NonEmptyVec<T, A> {
+    /// Wrapper around `Vec::dedup`. This method cannot leave the vector empty, and is as such safe to use.
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.0.dedup()
+    }
+}
+
+impl<T, A: Allocator> core::ops::Deref for NonEmptyVec<T, A> {
+    type Target = NonEmptySlice<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { NonEmptySlice::<T>::from_slice_unchecked(&self.0) }
+    }
+}
+
+impl<T, A: Allocator> core::ops::DerefMut for NonEmptyVec<T, A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { NonEmptySlice::<T>::from_slice_unchecked_mut(&mut self.0) }
+    }
+}
+
+impl<T: core::fmt::Debug, A: Allocator> core::fmt::Debug for NonEmptyVec<T, A> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+// Comparisons are implemented by hand, since deriving them would require `A` to implement them as well.
+
+impl<T: PartialEq, A1: Allocator, A2: Allocator> PartialEq<NonEmptyVec<T, A2>> for NonEmptyVec<T, A1> {
+    #[inline]
+    fn eq(&self, other: &NonEmptyVec<T, A2>) -> bool {
+        self.0[..] == other.0[..]
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for NonEmptyVec<T, A> {}
+
+impl<T: core::hash::Hash, A: Allocator> core::hash::Hash for NonEmptyVec<T, A> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T: PartialOrd, A: Allocator> PartialOrd for NonEmptyVec<T, A> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for NonEmptyVec<T, A> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T, A: Allocator> FromNonEmptyIterator<T> for NonEmptyVec<T, A> where A: Default {
+    #[inline]
+    fn from_non_empty_iter<I: NonEmptyIterator<Item = T>>(iter: I) -> NonEmptyVec<T, A> {
+        let mut vec = Vec::new_in(A::default());
+        vec.extend(iter);
+        NonEmptyVec(vec)
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for NonEmptyVec<T, A> {
+    type Item = T;
+    type IntoIter = allocator_api2::vec::IntoIter<T, A>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a NonEmptyVec<T, A> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut NonEmptyVec<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for NonEmptyVec<T, A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator + 'a> Extend<&'a T> for NonEmptyVec<T, A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T, A: Allocator> AsRef<[T]> for NonEmptyVec<T, A> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, A: Allocator> AsRef<NonEmptySlice<T>> for NonEmptyVec<T, A> {
+    #[inline]
+    fn as_ref(&self) -> &NonEmptySlice<T> {
+        self
+    }
+}
+
+// AsMut cannot be implemented for already mentioned reasons.
+
+impl<T, A: Allocator> TryFrom<Vec<T, A>> for NonEmptyVec<T, A> {
+    type Error = EmptyError<Vec<T, A>>;
+
+    #[inline]
+    fn try_from(vec: Vec<T, A>) -> Result<NonEmptyVec<T, A>, EmptyError<Vec<T, A>>> {
+        NonEmptyVec::try_from_vec(vec)
+    }
+}
+
+impl<T, A: Allocator> From<NonEmptyVec<T, A>> for Vec<T, A> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T, A>) -> Vec<T, A> {
+        vec.0
+    }
+}
+
+impl<T: Clone> From<&NonEmptySlice<T>> for NonEmptyVec<T> {
+    #[inline]
+    fn from(s: &NonEmptySlice<T>) -> NonEmptyVec<T> {
+        NonEmptyVec(Vec::from(s.get_slice()))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for NonEmptyVec<T> {
+    /// The length of the array is checked at compile time, and as such this method is infalible.
+    /// If the length of the array is not 0, a compiler error will be given. This requires a full build and does not show up when running `cargo check`.
+    #[inline]
+    fn from(arr: [T; N]) -> NonEmptyVec<T> {
+        const { assert!(N > 0, "Length of array must be non-zero to create NonEmptySlice."); }
+        NonEmptyVec(Vec::from(arr))
+    }
+}
+
+// `Global` allocates through the global allocator, just like `alloc::vec::Vec`,
+// so the raw parts can be handed over between both vector types without reallocating.
+
+impl<T> From<crate::NonEmptyVec<T>> for NonEmptyVec<T> {
+    #[inline]
+    fn from(vec: crate::NonEmptyVec<T>) -> NonEmptyVec<T> {
+        let mut vec = core::mem::ManuallyDrop::new(vec.0);
+        NonEmptyVec(unsafe { Vec::from_raw_parts(vec.as_mut_ptr(), vec.len(), vec.capacity()) })
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for crate::NonEmptyVec<T> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> crate::NonEmptyVec<T> {
+        let (ptr, len, capacity) = vec.0.into_raw_parts();
+        crate::NonEmptyVec(unsafe { alloc::vec::Vec::from_raw_parts(ptr, len, capacity) })
+    }
+}
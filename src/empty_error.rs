@@ -0,0 +1,53 @@
+/// The error returned when trying to create a non-empty type from an empty value.
+///
+/// The rejected value is kept inside the error, and can be recovered with `into_inner`.
+/// When there is nothing to give back, such as an exhausted iterator passed to `try_from_iter`, the value is `()`.
+/// Like `std::sync::PoisonError`, its `Debug` output does not include the value,
+/// so it implements `Error` whatever the type of the value is.
+///
+/// # Examples
+///
+/// ```
+/// # use non_empty_continuous::*;
+/// # #[cfg(feature = "alloc")] {
+/// let error = NonEmptyVec::<i32>::try_from_vec(vec![]).unwrap_err();
+/// assert_eq!(error.to_string(), "cannot create a non-empty type from an empty value");
+/// assert_eq!(error.into_inner(), vec![]);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EmptyError<C>(pub(crate) C);
+
+impl<C> EmptyError<C> {
+    /// Creates a new `EmptyError` holding the rejected value.
+    #[inline]
+    pub const fn new(value: C) -> EmptyError<C> {
+        EmptyError(value)
+    }
+
+    /// Moves the rejected value out of the error.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+
+    /// Gives a read-only reference to the rejected value.
+    #[inline]
+    pub const fn get_ref(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C> core::fmt::Debug for EmptyError<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EmptyError").finish_non_exhaustive()
+    }
+}
+
+impl<C> core::fmt::Display for EmptyError<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "cannot create a non-empty type from an empty value")
+    }
+}
+
+impl<C> core::error::Error for EmptyError<C> {}
@@ -9,6 +9,8 @@ This crate attempts to reimplement as much functionality as possible from the no
 # Examples
 
 ```
+# use non_empty_continuous::*;
+# #[cfg(feature = "alloc")] {
 let first_element = 10;
 let mut non_empty_vec: NonEmptyVec<i32> = NonEmptyVec::new(first_element);
 non_empty_vec.reserve(2);
@@ -17,35 +19,46 @@ non_empty_vec.push(30);
 _ = non_empty_vec.try_pop();
 
 let non_empty_slice: &NonEmptySlice<i32> = &non_empty_vec[..=1];
-let non_empty_slice_mut: &mut NonEmptySlice<i32> = &mut non_empty_vec[..];
+let length: core::num::NonZeroUsize = non_empty_slice.len();
 
-let length: std::num::NonZeroUsize = non_empty_slice.len();
+let non_empty_slice_mut: &mut NonEmptySlice<i32> = &mut non_empty_vec[..];
 
 let non_empty_vec_from_macro = ne_vec![99, 98, 97];
+# }
 ```
 
 Some operations allow for infalible operations with arrays whose length is checked not to be 0 at compile-time.
 
 ```
+# use non_empty_continuous::*;
+# #[cfg(feature = "alloc")] {
 let arr = [1, 2, 3];
 let mut non_empty_vec: NonEmptyVec<i32> = NonEmptyVec::from_arr(arr);
+# }
 ```
 
 \
 If the length of the array is not 0, a compiler error will be given. This requires a full build and does not show up when running `cargo check`.
-```
-let arr2 = [];
-let mut non_empty_vec: NonEmptyVec<i32> = NonEmptyVec::from_arr(arr); // !!!
+```compile_fail
+# use non_empty_continuous::*;
+let arr2: [i32; 0] = [];
+let mut non_empty_vec: NonEmptyVec<i32> = NonEmptyVec::from_arr(arr2); // !!!
 ```
 
 # Features
 
+## `std` and `alloc`
+`std` is enabled by default, and only implies `alloc`. Disabling default features makes the crate `no_std`.
+Without `alloc`, only `NonEmptySlice`, `NonEmptyStr` and `NonEmptyIterator` are available, since they only need `core`.
+
 ## `smallvec`
 Exposes `NonEmptySmallVec`, a non-empty wrapper around `SmallVec` from the `small_vec` crate.
 
 ```
+# #[cfg(feature = "smallvec")] {
+# use non_empty_continuous::*;
 let first_element = 10;
-let mut non_empty_small_vec: NonEmptySmallVec<[usize; 5]> = NonEmptySmallVec::new(first_element);
+let mut non_empty_small_vec: NonEmptySmallVec<[i32; 5]> = NonEmptySmallVec::new(first_element);
 non_empty_small_vec.reserve(2);
 non_empty_small_vec.push(20);
 non_empty_small_vec.push(30);
@@ -53,24 +66,104 @@ non_empty_small_vec.push(30);
 let _: bool = non_empty_small_vec.spilled();
 
 let non_empty_slice: &NonEmptySlice<i32> = &non_empty_small_vec[..=1];
+let _ = non_empty_slice.len();
+
 let non_empty_slice_mut: &mut NonEmptySlice<i32> = &mut non_empty_small_vec[..];
 
-let non_empty_smallvec_from_macro = ne_smallvec![99, 98, 97];
+let non_empty_smallvec_from_macro: NonEmptySmallVec<[i32; 3]> = ne_smallvec![99, 98, 97];
+# }
 ```
 
 \
 Smallvec can also has operations where the length of the array can be checked at compile-time.
 ```
+# #[cfg(feature = "smallvec")] {
+# use non_empty_continuous::*;
 let arr3 = [4, 5, 6];
-let mut non_empty_small_vec: NonEmptySmallVec<i32> = NonEmptySmallVec::from_arr(arr3);
+let mut non_empty_small_vec: NonEmptySmallVec<[i32; 3]> = NonEmptySmallVec::from_buf(arr3);
+# }
+```
+
+## `serde`
+Implements `Serialize` for `NonEmptySlice`, `NonEmptyVec` and `NonEmptySmallVec`, and `Deserialize` for 
+`NonEmptyVec`, `Box<NonEmptySlice>` and `NonEmptySmallVec`. Deserializing an empty sequence results in an error.
+
+```
+# #[cfg(feature = "serde")] {
+# use non_empty_continuous::*;
+let non_empty_vec: NonEmptyVec<i32> = serde_json::from_str("[1, 2, 3]").unwrap();
+assert_eq!(serde_json::to_string(&non_empty_vec).unwrap(), "[1,2,3]");
+
+let error = serde_json::from_str::<NonEmptyVec<i32>>("[]").unwrap_err();
+assert_eq!(error.to_string(), "invalid length 0, expected at least one element in NonEmptyVec");
+# }
+```
+
+## `num`
+Implements statistics on `NonEmptySlice`s of primitive numbers using the `num-traits` crate,
+such as `mean`, `median`, `variance` and `percentile`. They return plain values, since the slice is never empty.
+Methods that need to reorder the elements, like `median`, also require `alloc`.
+
+```
+# #[cfg(all(feature = "num", feature = "alloc"))] {
+# use non_empty_continuous::*;
+let non_empty_vec = ne_vec![4.0, 1.0, 3.0, 2.0];
+assert_eq!(non_empty_vec.mean(), 2.5);
+assert_eq!(non_empty_vec.median_interpolated(), 2.5);
+# }
+```
+
+## `allocator-api2`
+Exposes the `allocator_api` module, containing `NonEmptyVec<T, A: Allocator = Global>`, a non-empty wrapper around `Vec<T, A>`
+from the `allocator-api2` crate. This allows using custom allocators on stable, while the root `NonEmptyVec<T>` stays unchanged.
+
+```
+# #[cfg(feature = "allocator-api2")] {
+use non_empty_continuous::allocator_api::{Global, NonEmptyVec};
+
+let mut non_empty_vec: NonEmptyVec<i32> = NonEmptyVec::new_in(10, Global);
+non_empty_vec.push(20);
+let _: &Global = non_empty_vec.allocator();
+# }
 ```
 */
 
+#![no_std]
+
+#[cfg(feature = "alloc")] extern crate alloc;
+#[cfg(feature = "std")] extern crate std;
+
 mod non_empty_slice; 
 pub use non_empty_slice::*;
 
-#[macro_use] mod non_empty_vec;
-pub use non_empty_vec::*;
+mod non_empty_iter;
+pub use non_empty_iter::*;
+
+mod empty_error;
+pub use empty_error::*;
+
+mod semigroup;
+pub use semigroup::*;
+
+#[cfg(feature = "num")] mod statistics;
+#[cfg(feature = "num")] pub use statistics::*;
+
+#[cfg(feature = "alloc")] #[macro_use] mod vec_wrapper;
+#[cfg(feature = "alloc")] #[macro_use] mod non_empty_vec;
+#[cfg(feature = "alloc")] pub use non_empty_vec::*;
+
+#[cfg(feature = "alloc")] mod non_empty_vec_deque;
+#[cfg(feature = "alloc")] pub use non_empty_vec_deque::*;
+
+mod non_empty_str;
+pub use non_empty_str::*;
+
+#[cfg(feature = "alloc")] mod non_empty_string;
+#[cfg(feature = "alloc")] pub use non_empty_string::*;
 
 #[cfg(feature = "smallvec")] #[macro_use] mod non_empty_smallvec; 
-#[cfg(feature = "smallvec")] pub use non_empty_smallvec::*;
\ No newline at end of file
+#[cfg(feature = "smallvec")] pub use non_empty_smallvec::*;
+
+#[cfg(feature = "serde")] mod serde_impls;
+
+#[cfg(feature = "allocator-api2")] pub mod allocator_api;
@@ -0,0 +1,207 @@
+use core::num::NonZeroUsize;
+
+/// An iterator that is guaranteed to yield at least one item.
+///
+/// Types implementing this trait are not iterators themselves, they have to be turned into one through `IntoIterator`.
+/// This way the infallible methods provided here don't clash with their `Option`-returning counterparts on `Iterator`,
+/// and `for` loops still work as expected.
+///
+/// # Examples
+///
+/// ```
+/// # use non_empty_continuous::*;
+/// # #[cfg(feature = "alloc")] {
+/// let non_empty_vec = ne_vec![3, 1, 2];
+///
+/// let max: &i32 = non_empty_vec.iter_ne().max();
+/// let sum: i32 = non_empty_vec.iter_ne().copied().reduce(|a, b| a + b);
+/// assert_eq!((*max, sum), (3, 6));
+///
+/// for (index, item) in non_empty_vec.iter_ne().enumerate() {
+///     assert_eq!(non_empty_vec[index], *item);
+/// }
+/// # }
+/// ```
+///
+/// # Safety
+/// The iterator returned by `into_iter` must yield at least one item.
+/// The default methods of this trait rely on this, and use unchecked operations accordingly.
+pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
+    /// Splits the iterator into its first item and an iterator over the remaining ones.
+    #[inline]
+    fn next(self) -> (Self::Item, Self::IntoIter) {
+        let mut iter = self.into_iter();
+        let first = unsafe { iter.next().unwrap_unchecked() };
+        (first, iter)
+    }
+
+    /// Returns the first item of the iterator.
+    /// The iterator is guaranteed to have at least 1 item, so this method is infallible.
+    #[inline]
+    fn first(self) -> Self::Item {
+        self.next().0
+    }
+
+    /// Consumes the iterator, returning its last item.
+    /// The iterator is guaranteed to have at least 1 item, so this method is infallible.
+    #[inline]
+    fn last(self) -> Self::Item {
+        unsafe { self.into_iter().last().unwrap_unchecked() }
+    }
+
+    /// Consumes the iterator, counting the number of items, which is guaranteed not to be 0.
+    #[inline]
+    fn count(self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.into_iter().count()) }
+    }
+
+    /// Same as `Iterator::reduce`. The iterator is guaranteed to have at least 1 item, so this method is infallible.
+    #[inline]
+    fn reduce<F: FnMut(Self::Item, Self::Item) -> Self::Item>(self, f: F) -> Self::Item {
+        let (first, rest) = self.next();
+        rest.fold(first, f)
+    }
+
+    /// Same as `Iterator::min`. The iterator is guaranteed to have at least 1 item, so this method is infallible.\
+    /// If several items are equally minimum, the first one is returned.
+    #[inline]
+    fn min(self) -> Self::Item where Self::Item: Ord {
+        unsafe { self.into_iter().min().unwrap_unchecked() }
+    }
+
+    /// Same as `Iterator::max`. The iterator is guaranteed to have at least 1 item, so this method is infallible.\
+    /// If several items are equally maximum, the last one is returned.
+    #[inline]
+    fn max(self) -> Self::Item where Self::Item: Ord {
+        unsafe { self.into_iter().max().unwrap_unchecked() }
+    }
+
+    /// Same as `Iterator::min_by`. The iterator is guaranteed to have at least 1 item, so this method is infallible.\
+    /// If several items are equally minimum, the first one is returned.
+    #[inline]
+    fn min_by<F>(self, compare: F) -> Self::Item where F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering {
+        unsafe { self.into_iter().min_by(compare).unwrap_unchecked() }
+    }
+
+    /// Same as `Iterator::max_by`. The iterator is guaranteed to have at least 1 item, so this method is infallible.\
+    /// If several items are equally maximum, the last one is returned.
+    #[inline]
+    fn max_by<F>(self, compare: F) -> Self::Item where F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering {
+        unsafe { self.into_iter().max_by(compare).unwrap_unchecked() }
+    }
+
+    /// Same as `Iterator::min_by_key`. The iterator is guaranteed to have at least 1 item, so this method is infallible.\
+    /// If several items are equally minimum, the first one is returned.
+    #[inline]
+    fn min_by_key<K: Ord, F>(self, f: F) -> Self::Item where F: FnMut(&Self::Item) -> K {
+        unsafe { self.into_iter().min_by_key(f).unwrap_unchecked() }
+    }
+
+    /// Same as `Iterator::max_by_key`. The iterator is guaranteed to have at least 1 item, so this method is infallible.\
+    /// If several items are equally maximum, the last one is returned.
+    #[inline]
+    fn max_by_key<K: Ord, F>(self, f: F) -> Self::Item where F: FnMut(&Self::Item) -> K {
+        unsafe { self.into_iter().max_by_key(f).unwrap_unchecked() }
+    }
+
+    /// Same as `Iterator::collect`, but only for collections that can be created from a non-empty iterator.
+    /// Since the iterator has at least one item, this method is infallible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// # #[cfg(feature = "alloc")] {
+    /// let non_empty_vec = ne_vec![1, 2, 3];
+    /// let doubled: NonEmptyVec<i32> = non_empty_vec.iter_ne().map(|x| x * 2).collect();
+    /// assert_eq!(doubled, ne_vec![2, 4, 6]);
+    /// # }
+    /// ```
+    #[inline]
+    fn collect<B: FromNonEmptyIterator<Self::Item>>(self) -> B {
+        B::from_non_empty_iter(self)
+    }
+
+    /// Same as `Iterator::map`, preserving non-emptyness guarantees.
+    #[inline]
+    fn map<U, F: FnMut(Self::Item) -> U>(self, f: F) -> NonEmptyIter<core::iter::Map<Self::IntoIter, F>> {
+        NonEmptyIter(self.into_iter().map(f))
+    }
+
+    /// Same as `Iterator::enumerate`, preserving non-emptyness guarantees.
+    #[inline]
+    fn enumerate(self) -> NonEmptyIter<core::iter::Enumerate<Self::IntoIter>> {
+        NonEmptyIter(self.into_iter().enumerate())
+    }
+
+    /// Same as `Iterator::zip`. Both iterators need to be non-empty for the result to be non-empty.
+    #[inline]
+    fn zip<U: NonEmptyIterator>(self, other: U) -> NonEmptyIter<core::iter::Zip<Self::IntoIter, U::IntoIter>> {
+        NonEmptyIter(self.into_iter().zip(other))
+    }
+
+    /// Same as `Iterator::chain`. Since `self` is non-empty, `other` may be empty.
+    #[inline]
+    fn chain<U: IntoIterator<Item = Self::Item>>(self, other: U) -> NonEmptyIter<core::iter::Chain<Self::IntoIter, U::IntoIter>> {
+        NonEmptyIter(self.into_iter().chain(other))
+    }
+
+    /// Same as `Iterator::rev`, preserving non-emptyness guarantees.
+    #[inline]
+    fn rev(self) -> NonEmptyIter<core::iter::Rev<Self::IntoIter>> where Self::IntoIter: DoubleEndedIterator {
+        NonEmptyIter(self.into_iter().rev())
+    }
+
+    /// Same as `Iterator::cloned`, preserving non-emptyness guarantees.
+    #[inline]
+    fn cloned<'a, T: Clone + 'a>(self) -> NonEmptyIter<core::iter::Cloned<Self::IntoIter>> where Self: IntoIterator<Item = &'a T> {
+        NonEmptyIter(self.into_iter().cloned())
+    }
+
+    /// Same as `Iterator::copied`, preserving non-emptyness guarantees.
+    #[inline]
+    fn copied<'a, T: Copy + 'a>(self) -> NonEmptyIter<core::iter::Copied<Self::IntoIter>> where Self: IntoIterator<Item = &'a T> {
+        NonEmptyIter(self.into_iter().copied())
+    }
+}
+
+/// Conversion from a `NonEmptyIterator`, the non-empty counterpart of `FromIterator`.
+/// Used by `NonEmptyIterator::collect`.
+pub trait FromNonEmptyIterator<T>: Sized {
+    /// Creates a value from a non-empty iterator.
+    fn from_non_empty_iter<I: NonEmptyIterator<Item = T>>(iter: I) -> Self;
+}
+
+/// A wrapper around an iterator that is guaranteed to yield at least one item.
+///
+/// This is the type returned by `iter_ne`, `into_iter_ne` and the adapters on `NonEmptyIterator`.
+/// It does not implement `Iterator` itself, see `NonEmptyIterator` for why.
+#[derive(Clone, Debug)]
+pub struct NonEmptyIter<I>(pub(crate) I);
+
+impl<I: Iterator> NonEmptyIter<I> {
+    /// # Safety
+    /// `iter` must yield at least one item.
+    #[inline]
+    pub unsafe fn new_unchecked(iter: I) -> NonEmptyIter<I> {
+        NonEmptyIter(iter)
+    }
+
+    /// Moves the inner iterator out of the `NonEmptyIter`.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I: Iterator> IntoIterator for NonEmptyIter<I> {
+    type Item = I::Item;
+    type IntoIter = I;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+unsafe impl<I: Iterator> NonEmptyIterator for NonEmptyIter<I> {}
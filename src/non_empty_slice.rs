@@ -1,5 +1,11 @@
-use std::num::NonZeroUsize;
-
+use core::num::NonZeroUsize;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::empty_error::*;
+use crate::non_empty_iter::*;
+use crate::semigroup::*;
+#[cfg(feature = "alloc")]
 use crate::non_empty_vec::*;
 
 /// A continuous non-empty slice.
@@ -23,31 +29,31 @@ impl<T> NonEmptySlice<T> {
     /// The slice must not be empty.
     #[inline]
     pub const unsafe fn from_slice_unchecked(slice: &[T]) -> &NonEmptySlice<T> {
-        unsafe { std::mem::transmute(slice) }
+        unsafe { core::mem::transmute(slice) }
     }
 
     /// # Safety
     /// The slice must not be empty
     #[inline]
     pub unsafe fn from_slice_unchecked_mut(slice: &mut [T]) -> &mut NonEmptySlice<T> {
-        unsafe { std::mem::transmute(slice) }
+        unsafe { core::mem::transmute(slice) }
     }
 
-    /// Creates a new `&NonEmptySlice`, from a slice, returning `None` if the slice is empty.
+    /// Creates a new `&NonEmptySlice`, from a slice, returning an `EmptyError` containing the slice if it is empty.
     #[inline]
-    pub const fn try_from_slice(slice: &[T]) -> Result<&NonEmptySlice<T>, &[T]> {
+    pub const fn try_from_slice(slice: &[T]) -> Result<&NonEmptySlice<T>, EmptyError<&[T]>> {
         if slice.is_empty() {
-            Err(slice)
+            Err(EmptyError(slice))
         } else {
             Ok(unsafe { NonEmptySlice::from_slice_unchecked(slice) })
         }
     }
 
-    /// Creates a new `&mut NonEmptySlice`, from a slice, returning `None` if the slice is empty.
+    /// Creates a new `&mut NonEmptySlice`, from a slice, returning an `EmptyError` containing the slice if it is empty.
     #[inline]
-    pub fn try_from_slice_mut(slice: &mut [T]) -> Result<&mut NonEmptySlice<T>, &mut [T]> {
+    pub fn try_from_slice_mut(slice: &mut [T]) -> Result<&mut NonEmptySlice<T>, EmptyError<&mut [T]>> {
         if slice.is_empty() {
-            Err(slice)
+            Err(EmptyError(slice))
         } else {
             Ok(unsafe { NonEmptySlice::from_slice_unchecked_mut(slice) })
         }
@@ -96,13 +102,14 @@ impl<T> NonEmptySlice<T> {
     /// # Examples
     ///
     /// ```
-    /// let one_element = NonEmptySlice::new(&[1]).unwrap();
-    /// let three_elements = NonEmptySlice::new(&[1, 2, 3]).unwrap();
-    /// let two_elements = NonEmptySlice::new(&[1, 2]).unwrap();
+    /// # use non_empty_continuous::*;
+    /// let one_element = NonEmptySlice::from_arr(&[1]);
+    /// let three_elements = NonEmptySlice::from_arr(&[1, 2, 3]);
+    /// let two_elements = NonEmptySlice::from_arr(&[1, 2]);
     /// 
     /// assert!(one_element.has_just_1_element());
-    /// assert_ne!(three_elements.has_just_1_element());
-    /// assert_ne!(two_elements.has_just_1_element());
+    /// assert!(!three_elements.has_just_1_element());
+    /// assert!(!two_elements.has_just_1_element());
     /// ```
     #[inline]
     pub const fn has_just_1_element(&self) -> bool {
@@ -140,30 +147,539 @@ impl<T> NonEmptySlice<T> {
         unsafe { self.get_unchecked_mut(last_index) }
     }
 
+    /// Returns the first element and the rest of the slice.
+    /// The slice is guaranteed to have at least 1 item, so unlike `slice::split_first` this method is infallible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let non_empty_slice = NonEmptySlice::from_arr(&[1, 2, 3]);
+    /// let (first, rest) = non_empty_slice.split_first();
+    /// assert_eq!((first, rest), (&1, &[2, 3][..]));
+    ///
+    /// let (last, rest) = non_empty_slice.split_last();
+    /// assert_eq!((last, rest), (&3, &[1, 2][..]));
+    /// ```
+    #[inline]
+    pub fn split_first(&self) -> (&T, &[T]) {
+        unsafe { self.0.split_first().unwrap_unchecked() }
+    }
+
+    /// Returns the first element and the rest of the slice, mutably.
+    /// The slice is guaranteed to have at least 1 item, so unlike `slice::split_first_mut` this method is infallible.
+    #[inline]
+    pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+        unsafe { self.0.split_first_mut().unwrap_unchecked() }
+    }
+
+    /// Returns the last element and the rest of the slice.
+    /// The slice is guaranteed to have at least 1 item, so unlike `slice::split_last` this method is infallible.
+    #[inline]
+    pub fn split_last(&self) -> (&T, &[T]) {
+        unsafe { self.0.split_last().unwrap_unchecked() }
+    }
+
+    /// Returns the last element and the rest of the slice, mutably.
+    /// The slice is guaranteed to have at least 1 item, so unlike `slice::split_last_mut` this method is infallible.
+    #[inline]
+    pub fn split_last_mut(&mut self) -> (&mut T, &mut [T]) {
+        unsafe { self.0.split_last_mut().unwrap_unchecked() }
+    }
+
+    /// Returns the elements covered by `range` as a `NonEmptySlice`,
+    /// or `None` if the range is empty or out of bounds.\
+    /// Unlike indexing, any kind of range can be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let non_empty_slice = NonEmptySlice::from_arr(&[1, 2, 3, 4]);
+    /// assert_eq!(non_empty_slice.get_ne(1..3).map(NonEmptySlice::get_slice), Some(&[2, 3][..]));
+    /// assert!(non_empty_slice.get_ne(2..2).is_none());
+    /// assert!(non_empty_slice.get_ne(3..=4).is_none());
+    /// ```
+    #[inline]
+    pub fn get_ne<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Option<&NonEmptySlice<T>> {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.0.get(bounds).and_then(|slice| NonEmptySlice::try_from_slice(slice).ok())
+    }
+
+    /// Returns the elements covered by `range` as a mutable `NonEmptySlice`,
+    /// or `None` if the range is empty or out of bounds.\
+    /// Unlike indexing, any kind of range can be used.
+    #[inline]
+    pub fn get_ne_mut<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Option<&mut NonEmptySlice<T>> {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.0.get_mut(bounds).and_then(|slice| NonEmptySlice::try_from_slice_mut(slice).ok())
+    }
+
+    /// Wrapper around `slice::split_at`. Since `mid` is not 0, the first half is guaranteed not to be empty.\
+    /// Panics if `mid` is greater than the length of the slice.
+    #[inline]
+    pub fn split_at_ne(&self, mid: NonZeroUsize) -> (&NonEmptySlice<T>, &[T]) {
+        let (left, right) = self.0.split_at(mid.get());
+        (unsafe { NonEmptySlice::from_slice_unchecked(left) }, right)
+    }
+
+    /// Wrapper around `slice::split_at_mut`. Since `mid` is not 0, the first half is guaranteed not to be empty.\
+    /// Panics if `mid` is greater than the length of the slice.
+    #[inline]
+    pub fn split_at_ne_mut(&mut self, mid: NonZeroUsize) -> (&mut NonEmptySlice<T>, &mut [T]) {
+        let (left, right) = self.0.split_at_mut(mid.get());
+        (unsafe { NonEmptySlice::from_slice_unchecked_mut(left) }, right)
+    }
+
+    /// Returns a reference to the minimum element of the slice.
+    /// The slice is guaranteed to have at least 1 item, so this method is infallible.\
+    /// Same as `Iterator::min`, if several elements are equally minimum, the first one is returned.
+    /// 
+    /// Since `NonEmptyVec` and `NonEmptySmallVec` implement `Ord`, calling `min` or `max` on them directly
+    /// resolves to `Ord::min` and `Ord::max`. Use `as_slice().min()` and `as_slice().max()` instead.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let non_empty_slice = NonEmptySlice::from_arr(&[3, 1, 4, 1, 5]);
+    /// assert_eq!(*non_empty_slice.min(), 1);
+    /// assert_eq!(*non_empty_slice.max(), 5);
+    /// assert_eq!(non_empty_slice.position_min(), 1);
+    /// assert_eq!(non_empty_slice.minmax(), (&1, &5));
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(*ne_vec![2, 7].as_slice().max(), 7);
+    /// ```
+    #[inline]
+    pub fn min(&self) -> &T where T: Ord {
+        self.iter_ne().min()
+    }
+
+    /// Returns a reference to the maximum element of the slice.
+    /// The slice is guaranteed to have at least 1 item, so this method is infallible.\
+    /// Same as `Iterator::max`, if several elements are equally maximum, the last one is returned.
+    #[inline]
+    pub fn max(&self) -> &T where T: Ord {
+        self.iter_ne().max()
+    }
+
+    /// Returns a reference to the element that gives the minimum value with respect to `compare`.
+    /// The slice is guaranteed to have at least 1 item, so this method is infallible.\
+    /// Same as `Iterator::min_by`, if several elements are equally minimum, the first one is returned.
+    #[inline]
+    pub fn min_by<F>(&self, mut compare: F) -> &T where F: FnMut(&T, &T) -> core::cmp::Ordering {
+        self.iter_ne().min_by(|a, b| compare(a, b))
+    }
+
+    /// Returns a reference to the element that gives the maximum value with respect to `compare`.
+    /// The slice is guaranteed to have at least 1 item, so this method is infallible.\
+    /// Same as `Iterator::max_by`, if several elements are equally maximum, the last one is returned.
+    #[inline]
+    pub fn max_by<F>(&self, mut compare: F) -> &T where F: FnMut(&T, &T) -> core::cmp::Ordering {
+        self.iter_ne().max_by(|a, b| compare(a, b))
+    }
+
+    /// Returns a reference to the element that gives the minimum value of `f`.
+    /// The slice is guaranteed to have at least 1 item, so this method is infallible.\
+    /// Same as `Iterator::min_by_key`, if several elements are equally minimum, the first one is returned.
+    #[inline]
+    pub fn min_by_key<K: Ord, F>(&self, mut f: F) -> &T where F: FnMut(&T) -> K {
+        self.iter_ne().min_by_key(|x| f(x))
+    }
+
+    /// Returns a reference to the element that gives the maximum value of `f`.
+    /// The slice is guaranteed to have at least 1 item, so this method is infallible.\
+    /// Same as `Iterator::max_by_key`, if several elements are equally maximum, the last one is returned.
+    #[inline]
+    pub fn max_by_key<K: Ord, F>(&self, mut f: F) -> &T where F: FnMut(&T) -> K {
+        self.iter_ne().max_by_key(|x| f(x))
+    }
+
+    /// Returns references to both the minimum and the maximum element of the slice, in a single pass.
+    /// The slice is guaranteed to have at least 1 item, so this method is infallible.\
+    /// Ties are broken the same way as in `min` and `max`: the first minimum and the last maximum are returned.
+    #[inline]
+    pub fn minmax(&self) -> (&T, &T) where T: Ord {
+        let (first, rest) = self.iter_ne().next();
+        rest.fold((first, first), |(min, max), item| {
+            (if item < min { item } else { min }, if item >= max { item } else { max })
+        })
+    }
+
+    /// Returns the index of the minimum element of the slice.
+    /// The slice is guaranteed to have at least 1 item, so this method is infallible.\
+    /// Ties are broken the same way as in `min`: the index of the first minimum is returned.
+    #[inline]
+    pub fn position_min(&self) -> usize where T: Ord {
+        self.iter_ne().enumerate().min_by(|(_, a), (_, b)| a.cmp(b)).0
+    }
+
+    /// Returns the index of the maximum element of the slice.
+    /// The slice is guaranteed to have at least 1 item, so this method is infallible.\
+    /// Ties are broken the same way as in `max`: the index of the last maximum is returned.
+    #[inline]
+    pub fn position_max(&self) -> usize where T: Ord {
+        self.iter_ne().enumerate().max_by(|(_, a), (_, b)| a.cmp(b)).0
+    }
+
+    /// Returns an iterator over the slice, which is guaranteed to yield at least one item.
+    /// Consider using `iter` instead if the guarantee is not needed.
+    #[inline]
+    pub fn iter_ne(&self) -> NonEmptyIter<core::slice::Iter<'_, T>> {
+        NonEmptyIter(self.0.iter())
+    }
+
+    /// Returns an iterator that allows modifying each value, which is guaranteed to yield at least one item.
+    /// Consider using `iter_mut` instead if the guarantee is not needed.
+    #[inline]
+    pub fn iter_mut_ne(&mut self) -> NonEmptyIter<core::slice::IterMut<'_, T>> {
+        NonEmptyIter(self.0.iter_mut())
+    }
+
+    /// Same as `slice::chunks`, yielding `NonEmptySlice`s.
+    /// Since the slice is not empty, at least one chunk is always returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// # use core::num::NonZeroUsize;
+    /// let non_empty_slice = NonEmptySlice::from_arr(&[1, 2, 3, 4, 5]);
+    /// let (first, rest) = non_empty_slice.chunks_ne(NonZeroUsize::new(2).unwrap()).next();
+    /// assert_eq!(first.get_slice(), [1, 2]);
+    /// assert_eq!(rest.map(|chunk| *chunk.last()).collect::<Vec<_>>(), [4, 5]);
+    ///
+    /// let runs: Vec<usize> = non_empty_slice.chunk_by_ne(|a, b| b - a == 1).into_iter().map(|run| run.get_len()).collect();
+    /// assert_eq!(runs, [5]);
+    /// ```
+    #[inline]
+    pub fn chunks_ne(&self, chunk_size: NonZeroUsize) -> NonEmptyIter<NonEmptySubslices<core::slice::Chunks<'_, T>>> {
+        NonEmptyIter(NonEmptySubslices(self.0.chunks(chunk_size.get())))
+    }
+
+    /// Same as `slice::chunks_mut`, yielding mutable `NonEmptySlice`s.
+    /// Since the slice is not empty, at least one chunk is always returned.
+    #[inline]
+    pub fn chunks_ne_mut(&mut self, chunk_size: NonZeroUsize) -> NonEmptyIter<NonEmptySubslicesMut<core::slice::ChunksMut<'_, T>>> {
+        NonEmptyIter(NonEmptySubslicesMut(self.0.chunks_mut(chunk_size.get())))
+    }
+
+    /// Same as `slice::rchunks`, yielding `NonEmptySlice`s.
+    /// Since the slice is not empty, at least one chunk is always returned.
+    #[inline]
+    pub fn rchunks_ne(&self, chunk_size: NonZeroUsize) -> NonEmptyIter<NonEmptySubslices<core::slice::RChunks<'_, T>>> {
+        NonEmptyIter(NonEmptySubslices(self.0.rchunks(chunk_size.get())))
+    }
+
+    /// Same as `slice::rchunks_mut`, yielding mutable `NonEmptySlice`s.
+    /// Since the slice is not empty, at least one chunk is always returned.
+    #[inline]
+    pub fn rchunks_ne_mut(&mut self, chunk_size: NonZeroUsize) -> NonEmptyIter<NonEmptySubslicesMut<core::slice::RChunksMut<'_, T>>> {
+        NonEmptyIter(NonEmptySubslicesMut(self.0.rchunks_mut(chunk_size.get())))
+    }
+
+    /// Same as `slice::windows`, yielding `NonEmptySlice`s.\
+    /// If `size` is greater than the length of the slice, no windows are returned,
+    /// so unlike the other methods here the iterator itself may be empty.
+    #[inline]
+    pub fn windows_ne(&self, size: NonZeroUsize) -> NonEmptySubslices<core::slice::Windows<'_, T>> {
+        NonEmptySubslices(self.0.windows(size.get()))
+    }
+
+    /// Same as `slice::chunk_by`, yielding `NonEmptySlice`s.
+    /// Since the slice is not empty, at least one chunk is always returned.
+    #[inline]
+    pub fn chunk_by_ne<F>(&self, pred: F) -> NonEmptyIter<NonEmptySubslices<core::slice::ChunkBy<'_, T, F>>>
+    where F: FnMut(&T, &T) -> bool {
+        NonEmptyIter(NonEmptySubslices(self.0.chunk_by(pred)))
+    }
+
+    /// Same as `slice::chunk_by_mut`, yielding mutable `NonEmptySlice`s.
+    /// Since the slice is not empty, at least one chunk is always returned.
+    #[inline]
+    pub fn chunk_by_ne_mut<F>(&mut self, pred: F) -> NonEmptyIter<NonEmptySubslicesMut<core::slice::ChunkByMut<'_, T, F>>>
+    where F: FnMut(&T, &T) -> bool {
+        NonEmptyIter(NonEmptySubslicesMut(self.0.chunk_by_mut(pred)))
+    }
+
+    /// Same as `slice::split_inclusive`, yielding `NonEmptySlice`s.
+    /// Every piece contains at least its terminator, or is the non-empty remainder of the slice.
+    /// Since the slice is not empty, at least one piece is always returned.
+    #[inline]
+    pub fn split_inclusive_ne<F>(&self, pred: F) -> NonEmptyIter<NonEmptySubslices<core::slice::SplitInclusive<'_, T, F>>>
+    where F: FnMut(&T) -> bool {
+        NonEmptyIter(NonEmptySubslices(self.0.split_inclusive(pred)))
+    }
+
+    /// Same as `slice::split_inclusive_mut`, yielding mutable `NonEmptySlice`s.
+    /// Since the slice is not empty, at least one piece is always returned.
+    #[inline]
+    pub fn split_inclusive_ne_mut<F>(&mut self, pred: F) -> NonEmptyIter<NonEmptySubslicesMut<core::slice::SplitInclusiveMut<'_, T, F>>>
+    where F: FnMut(&T) -> bool {
+        NonEmptyIter(NonEmptySubslicesMut(self.0.split_inclusive_mut(pred)))
+    }
+
     /// `clone`s all elements of the slice into a new vector, 
     /// guaranteeing that the resulting vector is not empty.
+    #[cfg(feature = "alloc")]
     #[inline]
     pub fn to_vec(&self) -> NonEmptyVec<T> where T: Clone {
         NonEmptyVec(self.0.to_vec())
     }
 
     /// Safely converts a `Box<NonEmptySlice>` into a `NonEmptyVec`, upholding non-emptyness guarantees.
+    #[cfg(feature = "alloc")]
     #[inline]
     pub fn into_vec(self: Box<Self>) -> NonEmptyVec<T> {
-        let mut self_box = std::mem::ManuallyDrop::new(self);
-        let vec = unsafe { Vec::<T>::from_raw_parts(self_box.0.as_mut_ptr(), self_box.0.len(), self_box.0.len()) };
-        NonEmptyVec(vec)
+        let len = self.0.len();
+        let ptr = Box::into_raw(self) as *mut T;
+        NonEmptyVec(unsafe { Vec::<T>::from_raw_parts(ptr, len, len) })
     }
 
     /// `clone`s all elements of the slice into a new vector, repeated `n` times.
     /// The resulting vector is guaranteed not to be empty.
+    #[cfg(feature = "alloc")]
     #[inline]
     pub fn repeat(&self, n: NonZeroUsize) -> NonEmptyVec<T> where T: Copy {
         NonEmptyVec(self.0.repeat(n.get()))
     }
+
+    /// Maps every element of the slice by reference into a new vector, which is guaranteed not to be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let non_empty_vec = ne_vec!["a", "bc"];
+    /// assert_eq!(non_empty_vec.map_ref(|s| s.len()), ne_vec![1, 2]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn map_ref<U, F: FnMut(&T) -> U>(&self, f: F) -> NonEmptyVec<U> {
+        NonEmptyVec(self.0.iter().map(f).collect())
+    }
+
+    /// Pairs up the elements of two slices into a new vector, stopping at the end of the shorter one.\
+    /// Both slices are non-empty, so the resulting vector is too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let names = ne_vec!["id", "name", "age"];
+    /// let values = ne_vec![1, 2];
+    /// assert_eq!(names.zip(&values), ne_vec![(&"id", &1), (&"name", &2)]);
+    /// assert_eq!(names.zip_exact(&values), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn zip<'a, U>(&'a self, other: &'a NonEmptySlice<U>) -> NonEmptyVec<(&'a T, &'a U)> {
+        NonEmptyVec(self.0.iter().zip(&other.0).collect())
+    }
+
+    /// Pairs up the elements of two slices into a new vector, like `zip`.\
+    /// Returns `None` if the slices have different lengths.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn zip_exact<'a, U>(&'a self, other: &'a NonEmptySlice<U>) -> Option<NonEmptyVec<(&'a T, &'a U)>> {
+        if self.0.len() == other.0.len() {
+            Some(self.zip(other))
+        } else {
+            None
+        }
+    }
+
+    /// Returns references to the `k` smallest elements of the slice, sorted from smallest to largest.\
+    /// If `k` is larger than the length of the slice, every element is returned.
+    /// The slice is only partially sorted, using `select_nth_unstable`, so this runs in `O(n + k * log(k))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// # use core::num::NonZeroUsize;
+    /// let scores = ne_vec![30, 10, 50, 20, 40];
+    /// let k = NonZeroUsize::new(3).unwrap();
+    /// assert_eq!(scores.k_smallest(k), ne_vec![&10, &20, &30]);
+    /// assert_eq!(scores.k_largest(k), ne_vec![&50, &40, &30]);
+    /// assert_eq!(scores.k_largest(NonZeroUsize::new(9).unwrap()).len().get(), 5);
+    /// assert_eq!((scores.nth_smallest(1), scores.nth_smallest(9)), (&20, &50));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn k_smallest(&self, k: NonZeroUsize) -> NonEmptyVec<&T> where T: Ord {
+        self.k_smallest_by(k, T::cmp)
+    }
+
+    /// Returns references to the `k` smallest elements of the slice with respect to `compare`, sorted from smallest to largest.\
+    /// If `k` is larger than the length of the slice, every element is returned.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn k_smallest_by<F>(&self, k: NonZeroUsize, mut compare: F) -> NonEmptyVec<&T> where F: FnMut(&T, &T) -> core::cmp::Ordering {
+        NonEmptyVec(self.0.iter().collect()).into_k_smallest_by(k, |a: &&T, b: &&T| compare(a, b))
+    }
+
+    /// Returns references to the `k` elements of the slice that give the smallest values of `f`, sorted from smallest to largest.\
+    /// If `k` is larger than the length of the slice, every element is returned.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn k_smallest_by_key<K: Ord, F>(&self, k: NonZeroUsize, mut f: F) -> NonEmptyVec<&T> where F: FnMut(&T) -> K {
+        self.k_smallest_by(k, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Returns references to the `k` largest elements of the slice, sorted from largest to smallest.\
+    /// If `k` is larger than the length of the slice, every element is returned.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn k_largest(&self, k: NonZeroUsize) -> NonEmptyVec<&T> where T: Ord {
+        self.k_smallest_by(k, |a, b| b.cmp(a))
+    }
+
+    /// Returns references to the `k` largest elements of the slice with respect to `compare`, sorted from largest to smallest.\
+    /// If `k` is larger than the length of the slice, every element is returned.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn k_largest_by<F>(&self, k: NonZeroUsize, mut compare: F) -> NonEmptyVec<&T> where F: FnMut(&T, &T) -> core::cmp::Ordering {
+        self.k_smallest_by(k, |a, b| compare(b, a))
+    }
+
+    /// Returns references to the `k` elements of the slice that give the largest values of `f`, sorted from largest to smallest.\
+    /// If `k` is larger than the length of the slice, every element is returned.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn k_largest_by_key<K: Ord, F>(&self, k: NonZeroUsize, mut f: F) -> NonEmptyVec<&T> where F: FnMut(&T) -> K {
+        self.k_smallest_by(k, |a, b| f(b).cmp(&f(a)))
+    }
+
+    /// Returns a reference to the element at index `n` of the slice once sorted, without modifying the slice.\
+    /// `n` is clamped to the last index, so this method is infallible, returning the largest element if `n` is out of range.
+    /// Use `select_nth_smallest` to avoid allocating, by reordering the slice instead.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn nth_smallest(&self, n: usize) -> &T where T: Ord {
+        let mut items: Vec<&T> = self.0.iter().collect();
+        let n = n.min(items.len() - 1);
+        items.select_nth_unstable(n).1
+    }
+
+    /// Wrapper around `slice::select_nth_unstable`, returning the element at index `n` of the slice once sorted.\
+    /// `n` is clamped to the last index, so unlike `slice::select_nth_unstable` this method does not panic.
+    /// The slice is reordered so that smaller elements come before index `n`, and larger ones after it.
+    #[inline]
+    pub fn select_nth_smallest(&mut self, n: usize) -> &mut T where T: Ord {
+        let n = n.min(self.0.len() - 1);
+        self.0.select_nth_unstable(n).1
+    }
+
+    /// Folds every element into an accumulator, starting with a clone of the first element.\
+    /// The slice is guaranteed to have at least 1 item, so unlike `Iterator::fold` no initial value is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let non_empty_slice = NonEmptySlice::from_arr(&[3, 1, 2]);
+    /// assert_eq!(non_empty_slice.fold1(|acc, &n| acc * 10 + n), 312);
+    /// assert_eq!((non_empty_slice.sum1(), non_empty_slice.product1()), (6, 6));
+    /// ```
+    #[inline]
+    pub fn fold1<F: FnMut(T, &T) -> T>(&self, f: F) -> T where T: Clone {
+        let (first, rest) = self.split_first();
+        rest.iter().fold(first.clone(), f)
+    }
+
+    /// Adds up every element of the slice.
+    /// Unlike `Iterator::sum`, this does not need a zero value, since the slice is guaranteed not to be empty.
+    #[inline]
+    pub fn sum1(&self) -> T where T: Clone + core::ops::Add<Output = T> {
+        self.fold1(|acc, item| acc + item.clone())
+    }
+
+    /// Multiplies every element of the slice.
+    /// Unlike `Iterator::product`, this does not need a one value, since the slice is guaranteed not to be empty.
+    #[inline]
+    pub fn product1(&self) -> T where T: Clone + core::ops::Mul<Output = T> {
+        self.fold1(|acc, item| acc * item.clone())
+    }
+
+    /// Combines every element of the slice with `Semigroup::combine`, from first to last.
+    #[inline]
+    pub fn combine_all(&self) -> T where T: Clone + Semigroup {
+        self.fold1(|acc, item| acc.combine(item.clone()))
+    }
+
+    /// Replaces every element of the slice with the result of calling `f` on it, without allocating.
+    #[inline]
+    pub fn map_in_place<F: FnMut(&T) -> T>(&mut self, mut f: F) {
+        for item in &mut self.0 {
+            *item = f(item);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> NonEmptySlice<V> {
+    /// Flattens a slice of non-empty collections into a single vector, `clone`ing every element.\
+    /// Unlike `slice::concat`, the result is guaranteed not to be empty, and is allocated once with the summed length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let nested = ne_vec![ne_vec![1, 2], ne_vec![3]];
+    /// assert_eq!(nested.concat(), ne_vec![1, 2, 3]);
+    /// assert_eq!(nested.join(&[0]), ne_vec![1, 2, 0, 3]);
+    /// ```
+    #[inline]
+    pub fn concat<T: Clone>(&self) -> NonEmptyVec<T> where V: AsRef<NonEmptySlice<T>> {
+        self.join(&[])
+    }
+
+    /// Flattens a slice of non-empty collections into a single vector, placing `sep` between each of them.\
+    /// Like `concat`, the result is allocated once.
+    #[inline]
+    pub fn join<T: Clone>(&self, sep: &[T]) -> NonEmptyVec<T> where V: AsRef<NonEmptySlice<T>> {
+        let len = self.0.iter().map(|v| v.as_ref().0.len()).sum::<usize>() + sep.len() * (self.0.len() - 1);
+        let mut vec = Vec::with_capacity(len);
+        let (first, rest) = self.split_first();
+        vec.extend_from_slice(&first.as_ref().0);
+        for v in rest {
+            vec.extend_from_slice(sep);
+            vec.extend_from_slice(&v.as_ref().0);
+        }
+        NonEmptyVec(vec)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> NonEmptySlice<&'a NonEmptySlice<T>> {
+    /// Flattens a slice of `NonEmptySlice` references into a vector of references to their elements.\
+    /// The result is guaranteed not to be empty, and is allocated once with the summed length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let (a, b) = (ne_vec![1, 2], ne_vec![3]);
+    /// let nested = ne_vec![a.as_slice(), b.as_slice()];
+    /// assert_eq!(nested.flatten(), ne_vec![&1, &2, &3]);
+    /// ```
+    #[inline]
+    pub fn flatten(&self) -> NonEmptyVec<&'a T> {
+        let mut vec = Vec::with_capacity(self.0.iter().map(|s| s.0.len()).sum());
+        vec.extend(self.0.iter().flat_map(|s| &s.0));
+        NonEmptyVec(vec)
+    }
 }
 
-impl<T> std::ops::Deref for NonEmptySlice<T> {
+impl<T> AsRef<NonEmptySlice<T>> for NonEmptySlice<T> {
+    #[inline]
+    fn as_ref(&self) -> &NonEmptySlice<T> {
+        self
+    }
+}
+
+impl<T> core::ops::Deref for NonEmptySlice<T> {
     type Target = [T];
 
     #[inline]
@@ -172,23 +688,85 @@ impl<T> std::ops::Deref for NonEmptySlice<T> {
     }
 }
 
-impl<T> std::ops::DerefMut for NonEmptySlice<T> {
+impl<T> core::ops::DerefMut for NonEmptySlice<T> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
 
-impl<T: std::fmt::Debug> std::fmt::Debug for NonEmptySlice<T> {
+/// An iterator over sub-slices that are known not to be empty, yielding them as `NonEmptySlice`s.
+///
+/// Returned by `chunks_ne`, `windows_ne` and similar methods on `NonEmptySlice`.
+#[derive(Clone, Debug)]
+pub struct NonEmptySubslices<I>(pub(crate) I);
+
+impl<'a, T: 'a, I: Iterator<Item = &'a [T]>> Iterator for NonEmptySubslices<I> {
+    type Item = &'a NonEmptySlice<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|slice| unsafe { NonEmptySlice::from_slice_unchecked(slice) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T: 'a, I: DoubleEndedIterator<Item = &'a [T]>> DoubleEndedIterator for NonEmptySubslices<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|slice| unsafe { NonEmptySlice::from_slice_unchecked(slice) })
+    }
+}
+
+impl<'a, T: 'a, I: ExactSizeIterator<Item = &'a [T]>> ExactSizeIterator for NonEmptySubslices<I> {}
+
+impl<'a, T: 'a, I: core::iter::FusedIterator<Item = &'a [T]>> core::iter::FusedIterator for NonEmptySubslices<I> {}
+
+/// An iterator over mutable sub-slices that are known not to be empty, yielding them as `NonEmptySlice`s.
+///
+/// Returned by `chunks_ne_mut` and similar methods on `NonEmptySlice`.
+#[derive(Debug)]
+pub struct NonEmptySubslicesMut<I>(pub(crate) I);
+
+impl<'a, T: 'a, I: Iterator<Item = &'a mut [T]>> Iterator for NonEmptySubslicesMut<I> {
+    type Item = &'a mut NonEmptySlice<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|slice| unsafe { NonEmptySlice::from_slice_unchecked_mut(slice) })
+    }
+
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T: 'a, I: DoubleEndedIterator<Item = &'a mut [T]>> DoubleEndedIterator for NonEmptySubslicesMut<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|slice| unsafe { NonEmptySlice::from_slice_unchecked_mut(slice) })
+    }
+}
+
+impl<'a, T: 'a, I: ExactSizeIterator<Item = &'a mut [T]>> ExactSizeIterator for NonEmptySubslicesMut<I> {}
+
+impl<'a, T: 'a, I: core::iter::FusedIterator<Item = &'a mut [T]>> core::iter::FusedIterator for NonEmptySubslicesMut<I> {}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for NonEmptySlice<T> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", &self.0)
     }
 }
 
 
 
-impl<T> std::ops::Index<usize> for NonEmptySlice<T> {
+impl<T> core::ops::Index<usize> for NonEmptySlice<T> {
     type Output = T;
 
     #[inline]
@@ -197,7 +775,7 @@ impl<T> std::ops::Index<usize> for NonEmptySlice<T> {
     }
 }
 
-impl<T> std::ops::IndexMut<usize> for NonEmptySlice<T> {
+impl<T> core::ops::IndexMut<usize> for NonEmptySlice<T> {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
@@ -207,18 +785,18 @@ impl<T> std::ops::IndexMut<usize> for NonEmptySlice<T> {
 
 
 /// Returns self and as such is guaranteed to have at least 1 item.
-impl<T> std::ops::Index<std::ops::RangeFull> for NonEmptySlice<T> {
+impl<T> core::ops::Index<core::ops::RangeFull> for NonEmptySlice<T> {
     type Output = NonEmptySlice<T>;
 
     #[inline]
-    fn index(&self, _index: std::ops::RangeFull) -> &Self::Output {
+    fn index(&self, _index: core::ops::RangeFull) -> &Self::Output {
         self
     }
 }
 
-impl<T> std::ops::IndexMut<std::ops::RangeFull> for NonEmptySlice<T> {
+impl<T> core::ops::IndexMut<core::ops::RangeFull> for NonEmptySlice<T> {
     #[inline]
-    fn index_mut(&mut self, _index: std::ops::RangeFull) -> &mut Self::Output {
+    fn index_mut(&mut self, _index: core::ops::RangeFull) -> &mut Self::Output {
         self
     }
 }
@@ -226,26 +804,48 @@ impl<T> std::ops::IndexMut<std::ops::RangeFull> for NonEmptySlice<T> {
 
 
 // RangeToInclusive is guaranteed to have at least 1 item.
-impl<T> std::ops::Index<std::ops::RangeToInclusive<usize>> for NonEmptySlice<T> {
+impl<T> core::ops::Index<core::ops::RangeToInclusive<usize>> for NonEmptySlice<T> {
     type Output = NonEmptySlice<T>;
 
     #[inline]
-    fn index(&self, index: std::ops::RangeToInclusive<usize>) -> &Self::Output {
+    fn index(&self, index: core::ops::RangeToInclusive<usize>) -> &Self::Output {
         unsafe { NonEmptySlice::from_slice_unchecked(&self.0[index]) }
     }
 }
 
-impl<T> std::ops::IndexMut<std::ops::RangeToInclusive<usize>> for NonEmptySlice<T> {
+impl<T> core::ops::IndexMut<core::ops::RangeToInclusive<usize>> for NonEmptySlice<T> {
     #[inline]
-    fn index_mut(&mut self, index: std::ops::RangeToInclusive<usize>) -> &mut Self::Output {
+    fn index_mut(&mut self, index: core::ops::RangeToInclusive<usize>) -> &mut Self::Output {
         unsafe { NonEmptySlice::from_slice_unchecked_mut(&mut self.0[index]) }
     }
 }
 
 
 
+impl<'a, T> IntoIterator for &'a NonEmptySlice<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NonEmptySlice<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+
+
 impl<'a, T> TryFrom<&'a [T]> for &'a NonEmptySlice<T> {
-    type Error = &'a [T];
+    type Error = EmptyError<&'a [T]>;
 
     fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
         NonEmptySlice::try_from_slice(slice)
@@ -253,7 +853,7 @@ impl<'a, T> TryFrom<&'a [T]> for &'a NonEmptySlice<T> {
 }
 
 impl<'a, T> TryFrom<&'a mut [T]> for &'a NonEmptySlice<T> {
-    type Error = &'a mut [T];
+    type Error = EmptyError<&'a mut [T]>;
 
     fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
         NonEmptySlice::try_from_slice_mut(slice).map(|x| &*x)
@@ -261,7 +861,7 @@ impl<'a, T> TryFrom<&'a mut [T]> for &'a NonEmptySlice<T> {
 }
 
 impl<'a, T> TryFrom<&'a mut [T]> for &'a mut NonEmptySlice<T> {
-    type Error = &'a mut [T];
+    type Error = EmptyError<&'a mut [T]>;
 
     fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
         NonEmptySlice::try_from_slice_mut(slice)
@@ -291,4 +891,93 @@ impl<'a, T: Clone, const N: usize> From<&'a [T; N]> for &'a NonEmptySlice<T> {
     fn from(value: &'a [T; N]) -> Self {
         NonEmptySlice::from_arr(value)
     }
-}
\ No newline at end of file
+}
+
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> Clone for Box<NonEmptySlice<T>> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.to_vec().into_boxed_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> From<&NonEmptySlice<T>> for Box<NonEmptySlice<T>> {
+    #[inline]
+    fn from(non_empty_slice: &NonEmptySlice<T>) -> Self {
+        non_empty_slice.to_vec().into_boxed_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<NonEmptyVec<T>> for Box<NonEmptySlice<T>> {
+    #[inline]
+    fn from(non_empty_vec: NonEmptyVec<T>) -> Self {
+        non_empty_vec.into_boxed_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> From<[T; N]> for Box<NonEmptySlice<T>> {
+    /// The length of the array is checked at compile time, and as such this method is infalible.
+    /// If the length of the array is not 0, a compiler error will be given. This requires a full build and does not show up when running `cargo check`.
+    #[inline]
+    fn from(arr: [T; N]) -> Self {
+        NonEmptyVec::from(arr).into_boxed_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<Box<NonEmptySlice<T>>> for Box<[T]> {
+    #[inline]
+    fn from(non_empty_slice: Box<NonEmptySlice<T>>) -> Self {
+        unsafe { Box::from_raw(Box::into_raw(non_empty_slice) as *mut [T]) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<Box<NonEmptySlice<T>>> for NonEmptyVec<T> {
+    #[inline]
+    fn from(non_empty_slice: Box<NonEmptySlice<T>>) -> Self {
+        non_empty_slice.into_vec()
+    }
+}
+
+// `Rc` and `Arc` are built from their `[T]` counterparts, which have the same layout since `NonEmptySlice` is `#[repr(transparent)]`.
+
+#[cfg(feature = "alloc")]
+impl<T> From<NonEmptyVec<T>> for alloc::rc::Rc<NonEmptySlice<T>> {
+    #[inline]
+    fn from(non_empty_vec: NonEmptyVec<T>) -> Self {
+        let rc: alloc::rc::Rc<[T]> = non_empty_vec.0.into();
+        unsafe { alloc::rc::Rc::from_raw(alloc::rc::Rc::into_raw(rc) as *const NonEmptySlice<T>) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> From<&NonEmptySlice<T>> for alloc::rc::Rc<NonEmptySlice<T>> {
+    #[inline]
+    fn from(non_empty_slice: &NonEmptySlice<T>) -> Self {
+        let rc: alloc::rc::Rc<[T]> = non_empty_slice.0.into();
+        unsafe { alloc::rc::Rc::from_raw(alloc::rc::Rc::into_raw(rc) as *const NonEmptySlice<T>) }
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> From<NonEmptyVec<T>> for alloc::sync::Arc<NonEmptySlice<T>> {
+    #[inline]
+    fn from(non_empty_vec: NonEmptyVec<T>) -> Self {
+        let arc: alloc::sync::Arc<[T]> = non_empty_vec.0.into();
+        unsafe { alloc::sync::Arc::from_raw(alloc::sync::Arc::into_raw(arc) as *const NonEmptySlice<T>) }
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T: Clone> From<&NonEmptySlice<T>> for alloc::sync::Arc<NonEmptySlice<T>> {
+    #[inline]
+    fn from(non_empty_slice: &NonEmptySlice<T>) -> Self {
+        let arc: alloc::sync::Arc<[T]> = non_empty_slice.0.into();
+        unsafe { alloc::sync::Arc::from_raw(alloc::sync::Arc::into_raw(arc) as *const NonEmptySlice<T>) }
+    }
+}
@@ -1,7 +1,10 @@
-use std::num::NonZeroUsize;
+use core::num::NonZeroUsize;
+use alloc::{boxed::Box, vec::Vec};
 
 use smallvec::*;
 
+use crate::empty_error::*;
+use crate::non_empty_iter::*;
 use crate::non_empty_slice::*;
 
 /// The easiest way to create a non-empty smallvec.
@@ -10,9 +13,14 @@ use crate::non_empty_slice::*;
 /// 
 /// # Examples
 /// ```
-/// let non_empty_smallvec_from_macro = ne_smallvec![99, 98, 97];
-/// let non_empty_smallvec_from_macro2 = ne_smallvec![0; std::num::NonZeroUsize::new(100).unwrap()];
-/// let _ = ne_smallvec![]; // Error: Cannot make an empty NonEmptySmallVec
+/// # use non_empty_continuous::*;
+/// let non_empty_smallvec_from_macro: NonEmptySmallVec<[i32; 3]> = ne_smallvec![99, 98, 97];
+/// let non_empty_smallvec_from_macro2: NonEmptySmallVec<[i32; 4]> = ne_smallvec![0; core::num::NonZeroUsize::new(100).unwrap()];
+/// ```
+/// 
+/// ```compile_fail
+/// # use non_empty_continuous::*;
+/// let _: NonEmptySmallVec<[i32; 0]> = ne_smallvec![]; // Error: Cannot make an empty NonEmptySmallVec
 /// ```
 #[macro_export]
 macro_rules! ne_smallvec {
@@ -54,32 +62,56 @@ impl<A: Array> NonEmptySmallVec<A> {
     }
 
     /// Safely turns a `SmallVec` into a `NonEmptySmallVec` if the smallvec is not empty, 
-    /// otherwise an `Err` containing the original smallvec is returned.
+    /// otherwise an `EmptyError` containing the original smallvec is returned.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// let non_empty_smallvec: SmallVec<[i32; 10]> = smallvec![1, 2, 3];
-    /// assert_eq!(NonEmptySmallVec::try_from_smallvec(non_empty_smallvec), Ok(NonEmptySmallVec::from_buf([1, 2, 3]));
+    /// # use non_empty_continuous::*;
+    /// # use smallvec::{smallvec, SmallVec};
+    /// let non_empty_smallvec: SmallVec<[i32; 3]> = smallvec![1, 2, 3];
+    /// assert_eq!(NonEmptySmallVec::try_from_smallvec(non_empty_smallvec).unwrap(), NonEmptySmallVec::from_buf([1, 2, 3]));
     /// 
-    /// let empty_vec: SmallVec<[i32; 10]> = smallvec![];
-    /// assert_eq!(NonEmptySmallVec::try_from_vec(empty_vec), Err(smallvec![]));
+    /// let empty_vec: SmallVec<[i32; 3]> = smallvec![];
+    /// assert!(NonEmptySmallVec::try_from_smallvec(empty_vec).is_err());
     /// ```
     #[inline]
-    pub fn try_from_smallvec(smallvec: SmallVec<A>) -> Result<NonEmptySmallVec<A>, SmallVec<A>> {
-        if smallvec.is_empty() { Err(smallvec) }
+    pub fn try_from_smallvec(smallvec: SmallVec<A>) -> Result<NonEmptySmallVec<A>, EmptyError<SmallVec<A>>> {
+        if smallvec.is_empty() { Err(EmptyError(smallvec)) }
         else { Ok(NonEmptySmallVec(smallvec)) }
     }
 
-    /// If `smallvec` is empty, the vector is not modified and `None` is returned. 
+    /// Collects `iter` into a `NonEmptySmallVec`, returning an `EmptyError` if it yields no items.\
+    /// The lower bound of the iterator's size hint is reserved upfront, so exact-size iterators allocate at most once.
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = A::Item>>(iter: I) -> Result<NonEmptySmallVec<A>, EmptyError<()>> {
+        let mut iter = iter.into_iter();
+        match iter.next() {
+            Some(first) => Ok(NonEmptySmallVec::from_first_and_iter(first, iter)),
+            None => Err(EmptyError(())),
+        }
+    }
+
+    /// Creates a `NonEmptySmallVec` whose first element is `first`, followed by the items of `rest`.\
+    /// The lower bound of `rest`'s size hint is reserved upfront, so exact-size iterators allocate at most once.
+    #[inline]
+    pub fn from_first_and_iter<I: IntoIterator<Item = A::Item>>(first: A::Item, rest: I) -> NonEmptySmallVec<A> {
+        let rest = rest.into_iter();
+        let mut smallvec = SmallVec::with_capacity(rest.size_hint().0.saturating_add(1));
+        smallvec.push(first);
+        smallvec.extend(rest);
+        NonEmptySmallVec(smallvec)
+    }
+
+    /// If `smallvec` is empty, the vector is not modified and an `EmptyError` containing the reference is returned. 
     /// Otherwise, `smallvec`'s items are moved to the new `NonEmptySmallVec` and `smallvec` is emptied.
     #[inline]
-    pub fn try_from_smallvec_ref_mut(smallvec: &mut SmallVec<A>) -> Option<NonEmptySmallVec<A>> {
-        if smallvec.is_empty() { None }
+    pub fn try_from_smallvec_ref_mut(smallvec: &mut SmallVec<A>) -> Result<NonEmptySmallVec<A>, EmptyError<&mut SmallVec<A>>> {
+        if smallvec.is_empty() { Err(EmptyError(smallvec)) }
         else {
             let mut non_empty_vec = NonEmptySmallVec(SmallVec::new());
-            std::mem::swap(smallvec, &mut non_empty_vec.0);
-            Some(non_empty_vec)
+            core::mem::swap(smallvec, &mut non_empty_vec.0);
+            Ok(non_empty_vec)
         }
     }
 
@@ -111,6 +143,34 @@ impl<A: Array> NonEmptySmallVec<A> {
         self.0
     }
 
+    /// Creates a consuming iterator, which is guaranteed to yield at least one item.
+    /// Consider using `into_iter` instead if the guarantee is not needed.
+    #[inline]
+    pub fn into_iter_ne(self) -> NonEmptyIter<smallvec::IntoIter<A>> {
+        NonEmptyIter(self.0.into_iter())
+    }
+
+    /// Maps every element of the smallvec into a new `NonEmptySmallVec`, whose array type may be different.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let non_empty_smallvec: NonEmptySmallVec<[i32; 2]> = ne_smallvec![1, 2];
+    /// let doubled: NonEmptySmallVec<[i64; 4]> = non_empty_smallvec.map(|n| n as i64 * 2);
+    /// assert_eq!(doubled.as_slice().get_slice(), [2, 4]);
+    /// ```
+    #[inline]
+    pub fn map<B: Array, F: FnMut(A::Item) -> B::Item>(self, f: F) -> NonEmptySmallVec<B> {
+        NonEmptySmallVec(self.0.into_iter().map(f).collect())
+    }
+
+    /// Maps every element of the smallvec with a fallible function, stopping at the first error.
+    #[inline]
+    pub fn try_map<B: Array, E, F: FnMut(A::Item) -> Result<B::Item, E>>(self, f: F) -> Result<NonEmptySmallVec<B>, E> {
+        self.0.into_iter().map(f).collect::<Result<SmallVec<B>, E>>().map(NonEmptySmallVec)
+    }
+
     /// Exact wrapper for `SmallVec::into_vec`, exists only for convenience.\
     /// Same as self.get_smallvec().spilled().
     #[inline]
@@ -153,14 +213,17 @@ impl<A: Array> NonEmptySmallVec<A> {
         self.0.set_len(new_len.get())
     }
 
-    /// Safe wrapper around  `SmallVec::drain`.\
-    /// This method returns `None` and does not remove any elements if it coveres the whole vector.
+    /// Safe counterpart of `SmallVec::drain`.\
+    /// This method returns `None` and does not remove any elements if `range` covers the whole smallvec.
+    ///
+    /// Unlike `SmallVec::drain`, the elements are removed eagerly. A lazy `Drain` could be leaked,
+    /// and `SmallVec::drain` leaves the smallvec empty until it is dropped.
     #[inline]
-    pub fn drain<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> Option<smallvec::Drain<'_, A>> {
-        if range.contains(&0) && range.contains(&(self.len().get() - 1)) {
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Option<smallvec::IntoIter<A>> {
+        if range.contains(&0) && range.contains(&(self.get_len() - 1)) {
             None
         } else {
-            Some(self.0.drain(range))
+            Some(self.0.drain(range).collect::<SmallVec<A>>().into_iter())
         }
     }
 
@@ -168,23 +231,38 @@ impl<A: Array> NonEmptySmallVec<A> {
     /// For a safe version of this method, use `NonEmptySmallVec::drain`.
     /// # Safety
     /// `range` must not take up the entire vector.
+    /// The returned `Drain` must not be leaked, since the smallvec is left empty until it is dropped.
     #[inline]
-    pub unsafe fn drain_unchecked<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> smallvec::Drain<'_, A> {
+    pub unsafe fn drain_unchecked<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> smallvec::Drain<'_, A> {
         self.0.drain(range)
     }
 
-    
-    // Not sure if this is worth adding.
+    /// Safe counterpart of `SmallVec::drain_filter`.\
+    /// Removes and returns the elements for which `filter` returns `true`, in order,
+    /// but stops extracting once one element is left in the smallvec. `filter` is not called on any element after that.
+    ///
+    /// Unlike `SmallVec::drain_filter`, the elements are extracted eagerly. A lazy iterator could be leaked,
+    /// and `SmallVec::drain_filter` leaves the smallvec empty until its iterator is dropped.
+    #[inline]
+    pub fn extract_if<F: FnMut(&mut A::Item) -> bool>(&mut self, mut filter: F) -> smallvec::IntoIter<A> {
+        let mut remaining = self.get_len();
+        let extracted: SmallVec<A> = self.0.drain_filter(|item| {
+            let extract = remaining > 1 && filter(item);
+            remaining -= extract as usize;
+            extract
+        }).collect();
+        extracted.into_iter()
+    }
 
-    // /// Unsafe wrapper around `SmallVec::drain_filter.`\
-    // /// There is no safe counterpart to this method.
-    // /// # Safety
-    // /// The closure must return `true` at least once. This is very hard to prove 
-    // /// statically, and as such is highly discouraged if other options are available.
-    // #[inline]
-    // pub unsafe fn drain_filter_unchecked<F: FnMut(A::Item) -> bool>(&mut self) {
-    //     self.0.drain_filter()
-    // }
+    /// Unsafe wrapper around `SmallVec::drain_filter`.\
+    /// For a safe version of this method, use `NonEmptySmallVec::extract_if`.
+    /// # Safety
+    /// `filter` must not extract every element of the smallvec.
+    /// The returned iterator must not be leaked, since the smallvec is left empty until it is dropped.
+    #[inline]
+    pub unsafe fn extract_if_unchecked<F: FnMut(&mut A::Item) -> bool>(&mut self, filter: F) -> smallvec::DrainFilter<'_, A, F> {
+        self.0.drain_filter(filter)
+    }
 
 
     /// Wrapper for `SmallVec::push`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
@@ -324,6 +402,72 @@ impl<A: Array> NonEmptySmallVec<A> {
         self.0.remove(index)
     }
 
+    // `SmallVec::retain` cannot be implemented directly since the function may retain no items.
+    // The variants below call `f` exactly once per element, in order.
+
+    /// Safe wrapper around `SmallVec::retain`.\
+    /// Returns `false` and does not modify the smallvec if `f` would retain no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let mut non_empty_smallvec: NonEmptySmallVec<[i32; 4]> = ne_smallvec![1, 2, 3, 4];
+    /// assert!(non_empty_smallvec.try_retain(|x| x % 2 == 0));
+    /// assert_eq!(non_empty_smallvec.as_slice().get_slice(), [2, 4]);
+    ///
+    /// assert!(!non_empty_smallvec.try_retain(|x| *x > 10));
+    /// assert_eq!(non_empty_smallvec.as_slice().get_slice(), [2, 4]);
+    /// ```
+    #[inline]
+    pub fn try_retain<F: FnMut(&A::Item) -> bool>(&mut self, mut f: F) -> bool {
+        self.try_retain_mut(|item| f(item))
+    }
+
+    /// Safe wrapper around `SmallVec::retain_mut`.\
+    /// Returns `false` and does not remove any elements if `f` would retain no elements.
+    /// Elements may still have been modified by `f`.
+    #[inline]
+    pub fn try_retain_mut<F: FnMut(&mut A::Item) -> bool>(&mut self, f: F) -> bool {
+        crate::vec_wrapper::try_retain_mut(&mut self.0, f)
+    }
+
+    /// Same as `SmallVec::retain`, but keeps the first element if `f` would retain no elements.\
+    /// Returns `false` if that is the case.
+    #[inline]
+    pub fn retain_or_first<F: FnMut(&A::Item) -> bool>(&mut self, mut f: F) -> bool {
+        self.retain_mut_or_first(|item| f(item))
+    }
+
+    /// Same as `SmallVec::retain_mut`, but keeps the first element if `f` would retain no elements.\
+    /// Returns `false` if that is the case.
+    #[inline]
+    pub fn retain_mut_or_first<F: FnMut(&mut A::Item) -> bool>(&mut self, f: F) -> bool {
+        let retained = self.try_retain_mut(f);
+        if !retained {
+            self.0.truncate(1);
+        }
+        retained
+    }
+
+    /// Same as `SmallVec::retain`, but keeps the last element if `f` would retain no elements.\
+    /// Returns `false` if that is the case.
+    #[inline]
+    pub fn retain_or_last<F: FnMut(&A::Item) -> bool>(&mut self, mut f: F) -> bool {
+        self.retain_mut_or_last(|item| f(item))
+    }
+
+    /// Same as `SmallVec::retain_mut`, but keeps the last element if `f` would retain no elements.\
+    /// Returns `false` if that is the case.
+    #[inline]
+    pub fn retain_mut_or_last<F: FnMut(&mut A::Item) -> bool>(&mut self, f: F) -> bool {
+        let retained = self.try_retain_mut(f);
+        if !retained {
+            self.0.drain(..self.get_len() - 1);
+        }
+        retained
+    }
+
     /// Wrapper around `SmallVec::dedup_by_key`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
     /// Dedup cannot leave the vector empty so this method is safe to use.
     #[inline]
@@ -363,6 +507,77 @@ impl<A: Array> NonEmptySmallVec<A> {
     pub fn try_grow(&mut self, new_cap: NonZeroUsize) -> Result<(), CollectionAllocErr> {
         self.0.try_grow(new_cap.get())
     }
+
+    /// Splits the smallvec in two at `at`, returning the elements from `at` onwards as a `NonEmptySmallVec`.\
+    /// Both halves must be non-empty, meaning `at` must be between 1 and `self.len() - 1`.
+    /// Otherwise, including when `at` is out of bounds, an `EmptyError` is returned and `self` is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let mut non_empty_smallvec: NonEmptySmallVec<[i32; 3]> = ne_smallvec![1, 2, 3];
+    /// assert!(non_empty_smallvec.try_split_off(0).is_err());
+    /// assert!(non_empty_smallvec.try_split_off(3).is_err());
+    /// let tail = non_empty_smallvec.try_split_off(1).unwrap();
+    /// assert_eq!((non_empty_smallvec.as_slice().get_slice(), tail.as_slice().get_slice()), (&[1][..], &[2, 3][..]));
+    /// ```
+    #[inline]
+    pub fn try_split_off(&mut self, at: usize) -> Result<NonEmptySmallVec<A>, EmptyError<()>> {
+        if at == 0 || at >= self.0.len() {
+            Err(EmptyError(()))
+        } else {
+            Ok(NonEmptySmallVec(self.0.drain(at..).collect()))
+        }
+    }
+
+    /// Removes the last `n` elements of the smallvec and returns them as a `NonEmptySmallVec`.\
+    /// Returns `None` and leaves `self` untouched if `n` is not less than the length of the smallvec,
+    /// since `self` would become empty.
+    #[inline]
+    pub fn split_off_last(&mut self, n: NonZeroUsize) -> Option<NonEmptySmallVec<A>> {
+        self.try_split_off(self.0.len().checked_sub(n.get())?).ok()
+    }
+
+    /// Splits the smallvec in two at `at`, returning both halves as `NonEmptySmallVec`s.\
+    /// Fails in the same cases as `try_split_off`, in which case `self` is given back as the error, since it was moved in.
+    #[inline]
+    pub fn split_at_owned(mut self, at: usize) -> Result<(NonEmptySmallVec<A>, NonEmptySmallVec<A>), NonEmptySmallVec<A>> {
+        match self.try_split_off(at) {
+            Ok(tail) => Ok((self, tail)),
+            Err(_) => Err(self),
+        }
+    }
+
+    /// Gives mutable access to the inner smallvec through a `SmallVecGuard`, which is checked not to be empty once it's done.\
+    /// The smallvec is replaced by `[fallback]` for as long as the guard lives, which is also what remains
+    /// if the edited smallvec ends up empty. Otherwise, the edits are kept and `fallback` is dropped.\
+    /// Unlike `edit`, this method doesn't need to clone the smallvec, but the previous state cannot be restored.
+    #[inline]
+    pub fn edit_or(&mut self, fallback: A::Item) -> SmallVecGuard<'_, A> {
+        let smallvec = core::mem::replace(&mut self.0, smallvec![fallback]);
+        SmallVecGuard { non_empty_smallvec: self, smallvec }
+    }
+}
+
+
+
+impl<X, Y, A: Array<Item = (X, Y)>> NonEmptySmallVec<A> {
+    /// Splits a smallvec of pairs into two smallvecs, which are both guaranteed not to be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let pairs: NonEmptySmallVec<[(&str, i32); 2]> = ne_smallvec![("id", 1), ("name", 2)];
+    /// let (names, values): (NonEmptySmallVec<[&str; 2]>, NonEmptySmallVec<[i32; 2]>) = pairs.unzip();
+    /// assert_eq!(names.as_slice().get_slice(), ["id", "name"]);
+    /// ```
+    #[inline]
+    pub fn unzip<B: Array<Item = X>, C: Array<Item = Y>>(self) -> (NonEmptySmallVec<B>, NonEmptySmallVec<C>) {
+        let (b, c) = self.0.into_iter().unzip();
+        (NonEmptySmallVec(b), NonEmptySmallVec(c))
+    }
 }
 
 
@@ -412,11 +627,96 @@ impl<A: Array> NonEmptySmallVec<A> where A::Item: Clone {
     pub fn from_elem(elem: A::Item, n: NonZeroUsize) -> NonEmptySmallVec<A> {
         NonEmptySmallVec(SmallVec::from_elem(elem, n.get()))
     }
+
+    /// Gives mutable access to a clone of the inner smallvec through a `SmallVecGuard`.\
+    /// The edits are applied by `SmallVecGuard::commit` or when the guard is dropped, unless the edited smallvec is empty,
+    /// in which case the smallvec keeps its previous state.
+    /// Use `edit_or` to avoid cloning the smallvec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let mut non_empty_smallvec: NonEmptySmallVec<[i32; 3]> = ne_smallvec![3, 1, 2];
+    /// non_empty_smallvec.edit().retain(|x| *x > 1);
+    /// assert_eq!(non_empty_smallvec.as_slice().get_slice(), [3, 2]);
+    ///
+    /// let mut guard = non_empty_smallvec.edit();
+    /// guard.clear();
+    /// assert!(guard.commit().is_err());
+    /// assert_eq!(non_empty_smallvec.as_slice().get_slice(), [3, 2]);
+    /// ```
+    #[inline]
+    pub fn edit(&mut self) -> SmallVecGuard<'_, A> {
+        let smallvec = self.0.clone();
+        SmallVecGuard { non_empty_smallvec: self, smallvec }
+    }
 }
 
+/// Mutable access to the smallvec behind a `NonEmptySmallVec`, created by `NonEmptySmallVec::edit` and `NonEmptySmallVec::edit_or`.
+///
+/// This type implements `DerefMut<Target = SmallVec<A>>`, so any `SmallVec` method can be used, including ones that may leave it empty.
+/// The smallvec is checked not to be empty when the guard is committed or dropped.
+/// Meanwhile, the `NonEmptySmallVec` is left in a valid state, so leaking the guard is safe.
+pub struct SmallVecGuard<'a, A: Array> {
+    non_empty_smallvec: &'a mut NonEmptySmallVec<A>,
+    smallvec: SmallVec<A>,
+}
 
+impl<A: Array> SmallVecGuard<'_, A> {
+    /// Applies the edits if the edited smallvec is not empty.
+    /// Otherwise, an `EmptyError` containing the empty smallvec is returned, and the `NonEmptySmallVec` is left as it was before editing
+    /// (or as `[fallback]` if the guard was created by `edit_or`).
+    #[inline]
+    pub fn commit(mut self) -> Result<(), EmptyError<SmallVec<A>>> {
+        self.finish()
+    }
 
-impl<A: Array> std::ops::Deref for NonEmptySmallVec<A> {
+    fn finish(&mut self) -> Result<(), EmptyError<SmallVec<A>>> {
+        let smallvec = core::mem::take(&mut self.smallvec);
+        if smallvec.is_empty() {
+            Err(EmptyError(smallvec))
+        } else {
+            self.non_empty_smallvec.0 = smallvec;
+            Ok(())
+        }
+    }
+}
+
+impl<A: Array> core::ops::Deref for SmallVecGuard<'_, A> {
+    type Target = SmallVec<A>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.smallvec
+    }
+}
+
+impl<A: Array> core::ops::DerefMut for SmallVecGuard<'_, A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.smallvec
+    }
+}
+
+impl<A: Array> core::fmt::Debug for SmallVecGuard<'_, A> where A::Item: core::fmt::Debug {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.smallvec)
+    }
+}
+
+/// Same as `SmallVecGuard::commit`, ignoring the result.
+impl<A: Array> Drop for SmallVecGuard<'_, A> {
+    #[inline]
+    fn drop(&mut self) {
+        _ = self.finish();
+    }
+}
+
+
+
+impl<A: Array> core::ops::Deref for NonEmptySmallVec<A> {
     type Target = NonEmptySlice<A::Item>;
     
     #[inline]
@@ -425,7 +725,7 @@ impl<A: Array> std::ops::Deref for NonEmptySmallVec<A> {
     }
 }
 
-impl<A: Array> std::ops::DerefMut for NonEmptySmallVec<A> {
+impl<A: Array> core::ops::DerefMut for NonEmptySmallVec<A> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe { NonEmptySlice::from_slice_unchecked_mut(&mut self.0) }
@@ -441,8 +741,8 @@ impl<A: Array> Extend<A::Item> for NonEmptySmallVec<A> {
 }
 
 
-impl<A: Array> std::fmt::Debug for NonEmptySmallVec<A> where A::Item: std::fmt::Debug {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<A: Array> core::fmt::Debug for NonEmptySmallVec<A> where A::Item: core::fmt::Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.0)
     }
 }
@@ -466,24 +766,24 @@ impl<A: Array, B: Array> PartialEq<NonEmptySmallVec<A>> for NonEmptySmallVec<B>
 impl<A: Array> Eq for NonEmptySmallVec<A> where A::Item: Eq {}
 
 impl<A: Array> PartialOrd for NonEmptySmallVec<A> where A::Item: PartialOrd {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         self.0.partial_cmp(&other.0)
     }
 }
 
 impl<A: Array> Ord for NonEmptySmallVec<A> where A::Item: Ord {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.0.cmp(&other.0)
     }
 }
 
-impl<A: Array> std::hash::Hash for NonEmptySmallVec<A> where A::Item: std::hash::Hash {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl<A: Array> core::hash::Hash for NonEmptySmallVec<A> where A::Item: core::hash::Hash {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.0.hash(state)
     }
 }
 
-impl<A: Array> std::iter::IntoIterator for NonEmptySmallVec<A> {
+impl<A: Array> core::iter::IntoIterator for NonEmptySmallVec<A> {
     type IntoIter = smallvec::IntoIter<A>;
     type Item = A::Item;
     
@@ -492,6 +792,14 @@ impl<A: Array> std::iter::IntoIterator for NonEmptySmallVec<A> {
     }
 }
 
+impl<A: Array> FromNonEmptyIterator<A::Item> for NonEmptySmallVec<A> {
+    #[inline]
+    fn from_non_empty_iter<I: NonEmptyIterator<Item = A::Item>>(iter: I) -> Self {
+        let (first, rest) = iter.next();
+        NonEmptySmallVec::from_first_and_iter(first, rest)
+    }
+}
+
 // `iter` and `iter_mut` for `NonEmptySmallVec` are not implemented since it dereferences to `NonEmptySlice` anyway.
 
 
@@ -557,7 +865,7 @@ impl<A: Array> core::borrow::BorrowMut<NonEmptySlice<A::Item>> for NonEmptySmall
 
 
 impl<A: Array> TryFrom<SmallVec<A>> for NonEmptySmallVec<A> {
-    type Error = SmallVec<A>;
+    type Error = EmptyError<SmallVec<A>>;
 
     #[inline]
     fn try_from(small_vec: SmallVec<A>) -> Result<Self, Self::Error> {
@@ -594,6 +902,8 @@ impl<T, const N: usize>  NonEmptySmallVec<[T; N]> {
     /// Wrapper around `SmallVec::from_buf_and_len_unchecked`.\
     /// The length of the array is checked at compile time, and as such this method is infalible.
     /// If the length of the array is not 0, a compiler error will be given. This requires a full build and does not show up when running `cargo check`.
+    /// # Safety
+    /// Same requirements as `SmallVec::from_buf_and_len_unchecked` apply.
     #[inline]
     pub unsafe fn from_buf_and_len_unchecked(buf: core::mem::MaybeUninit<[T; N]>, len: NonZeroUsize) -> NonEmptySmallVec<[T; N]> {
         const { assert!(N > 0, "Length of array must be non-zero to create NonEmptySmallVec."); }
@@ -611,7 +921,7 @@ impl<T, const N: usize> From<[T; N]> for NonEmptySmallVec<[T; N]> {
 
 
 
-#[cfg(feature = "dep:smallvec/write")]
+#[cfg(feature = "std")]
 impl<A: Array<Item = u8>> std::io::Write for NonEmptySmallVec<A> {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
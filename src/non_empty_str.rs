@@ -0,0 +1,205 @@
+use core::num::NonZeroUsize;
+
+use crate::empty_error::*;
+use crate::non_empty_slice::*;
+#[cfg(feature = "alloc")]
+use crate::non_empty_string::*;
+
+/// A non-empty string slice.
+///
+/// This type is a thin wrapper directly around `str`, and as such is un`Sized`.
+/// To use it, it'll have to be behind some form of indirection, such as `&NonEmptyStr` or `&mut NonEmptyStr`.
+///
+/// A mutable reference to a `str` cannot change its length, so this type implements `DerefMut<Target = str>`.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyStr(pub(crate) str);
+
+impl NonEmptyStr {
+    /// # Safety
+    /// The string slice must not be empty.
+    #[inline]
+    pub const unsafe fn from_str_unchecked(s: &str) -> &NonEmptyStr {
+        unsafe { core::mem::transmute(s) }
+    }
+
+    /// # Safety
+    /// The string slice must not be empty.
+    #[inline]
+    pub unsafe fn from_str_unchecked_mut(s: &mut str) -> &mut NonEmptyStr {
+        unsafe { core::mem::transmute(s) }
+    }
+
+    /// Creates a new `&NonEmptyStr` from a string slice, returning an `EmptyError` containing the string slice if it is empty.
+    #[inline]
+    pub const fn try_from_str(s: &str) -> Result<&NonEmptyStr, EmptyError<&str>> {
+        if s.is_empty() {
+            Err(EmptyError(s))
+        } else {
+            Ok(unsafe { NonEmptyStr::from_str_unchecked(s) })
+        }
+    }
+
+    /// Creates a new `&mut NonEmptyStr` from a string slice, returning an `EmptyError` containing the string slice if it is empty.
+    #[inline]
+    pub fn try_from_str_mut(s: &mut str) -> Result<&mut NonEmptyStr, EmptyError<&mut str>> {
+        if s.is_empty() {
+            Err(EmptyError(s))
+        } else {
+            Ok(unsafe { NonEmptyStr::from_str_unchecked_mut(s) })
+        }
+    }
+
+    /// Gets the underlying string slice behind the `NonEmptyStr`.
+    /// This type implements `Deref<Target = str>`, consider simply borrowing the value.
+    #[inline]
+    pub const fn get_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Gets the underlying mutable string slice behind the `NonEmptyStr`.
+    /// This type implements `DerefMut<Target = str>`, consider simply borrowing the value.
+    #[inline]
+    pub fn get_str_mut(&mut self) -> &mut str {
+        &mut self.0
+    }
+
+    /// Returns the length of the string in bytes, guaranteeing that it won't be 0.
+    /// If you need the result to be a `usize`, use `get_len` instead.
+    #[inline]
+    pub const fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+
+    /// Returns the length of the string in bytes, as a `usize`.
+    /// If you need the result to be a `NonZeroUsize`, use `len` instead.
+    #[inline]
+    pub const fn get_len(&self) -> usize {
+        self.len().get()
+    }
+
+    /// Returns the first character of the string.
+    /// The string is guaranteed to have at least 1 character, so this method is infallible.
+    #[inline]
+    pub fn first_char(&self) -> char {
+        unsafe { self.0.chars().next().unwrap_unchecked() }
+    }
+
+    /// Returns the last character of the string.
+    /// The string is guaranteed to have at least 1 character, so this method is infallible.
+    #[inline]
+    pub fn last_char(&self) -> char {
+        unsafe { self.0.chars().next_back().unwrap_unchecked() }
+    }
+
+    /// Returns the bytes of the string, preserving non-emptyness guarantees.
+    #[inline]
+    pub const fn as_bytes(&self) -> &NonEmptySlice<u8> {
+        unsafe { NonEmptySlice::from_slice_unchecked(self.0.as_bytes()) }
+    }
+}
+
+impl core::ops::Deref for NonEmptyStr {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for NonEmptyStr {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl core::fmt::Debug for NonEmptyStr {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl core::fmt::Display for NonEmptyStr {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl AsRef<str> for NonEmptyStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for NonEmptyStr {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl alloc::borrow::ToOwned for NonEmptyStr {
+    type Owned = NonEmptyString;
+
+    #[inline]
+    fn to_owned(&self) -> NonEmptyString {
+        NonEmptyString(self.0.into())
+    }
+}
+
+
+
+impl<'a> TryFrom<&'a str> for &'a NonEmptyStr {
+    type Error = EmptyError<&'a str>;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        NonEmptyStr::try_from_str(s)
+    }
+}
+
+impl<'a> TryFrom<&'a mut str> for &'a mut NonEmptyStr {
+    type Error = EmptyError<&'a mut str>;
+
+    #[inline]
+    fn try_from(s: &'a mut str) -> Result<Self, Self::Error> {
+        NonEmptyStr::try_from_str_mut(s)
+    }
+}
+
+impl<'a> TryFrom<&'a NonEmptySlice<u8>> for &'a NonEmptyStr {
+    type Error = core::str::Utf8Error;
+
+    /// Checks that the bytes are valid UTF-8. They are already known not to be empty.
+    #[inline]
+    fn try_from(bytes: &'a NonEmptySlice<u8>) -> Result<Self, Self::Error> {
+        core::str::from_utf8(bytes).map(|s| unsafe { NonEmptyStr::from_str_unchecked(s) })
+    }
+}
+
+impl<'a> From<&'a NonEmptyStr> for &'a str {
+    #[inline]
+    fn from(s: &'a NonEmptyStr) -> &'a str {
+        &s.0
+    }
+}
+
+impl PartialEq<str> for NonEmptyStr {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<&str> for NonEmptyStr {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        &self.0 == *other
+    }
+}
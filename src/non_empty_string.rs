@@ -0,0 +1,340 @@
+use core::num::NonZeroUsize;
+use alloc::{borrow::ToOwned, string::String};
+
+use crate::empty_error::*;
+use crate::non_empty_str::*;
+use crate::non_empty_vec::*;
+
+/// A non-empty, growable string.
+///
+/// Getting direct mutable acces to the inner string is not allowed,
+/// since that way making the string empty becomes possible.
+/// As such, many methods that mutate the inner string are re-implemented.
+///
+/// # Examples
+///
+/// ```
+/// # use non_empty_continuous::*;
+/// let mut non_empty_string = NonEmptyString::new('a');
+/// non_empty_string.push_str("bc");
+/// assert_eq!(non_empty_string.try_pop(), Some('c'));
+/// assert_eq!(non_empty_string.try_pop(), Some('b'));
+/// assert_eq!(non_empty_string.try_pop(), None);
+///
+/// assert_eq!(non_empty_string.first_char(), 'a');
+/// assert!(NonEmptyString::try_from("").is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyString(pub(crate) String);
+
+impl NonEmptyString {
+    /// Creates a new `NonEmptyString` containing just `ch`.
+    #[inline]
+    pub fn new(ch: char) -> NonEmptyString {
+        NonEmptyString(ch.into())
+    }
+
+    /// Creates a new `NonEmptyString` containing just `ch`, with a stated capacity in bytes
+    /// (unless it's smaller than the length of `ch`, in which case that will be the capacity).
+    #[inline]
+    pub fn with_capacity(ch: char, capacity: usize) -> NonEmptyString {
+        let mut string = String::with_capacity(capacity);
+        string.push(ch);
+        NonEmptyString(string)
+    }
+
+    /// Safely turns a `String` into a `NonEmptyString` if the string is not empty,
+    /// otherwise an `EmptyError` containing the original string is returned.
+    #[inline]
+    pub fn try_from_string(string: String) -> Result<NonEmptyString, EmptyError<String>> {
+        if string.is_empty() { Err(EmptyError(string)) }
+        else { Ok(NonEmptyString(string)) }
+    }
+
+    /// # Safety
+    /// `string` must not be empty.
+    #[inline]
+    pub unsafe fn from_string_unchecked(string: String) -> NonEmptyString {
+        NonEmptyString(string)
+    }
+
+    /// Converts a non-empty vector of bytes into a `NonEmptyString`, checking that the bytes are valid UTF-8.
+    /// Wrapper around `String::from_utf8`.
+    #[inline]
+    pub fn from_utf8(vec: NonEmptyVec<u8>) -> Result<NonEmptyString, alloc::string::FromUtf8Error> {
+        String::from_utf8(vec.0).map(NonEmptyString)
+    }
+
+    /// Moves the inner string out of the `NonEmptyString`.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Converts the string into its bytes, preserving non-emptyness guarantees.
+    #[inline]
+    pub fn into_bytes(self) -> NonEmptyVec<u8> {
+        NonEmptyVec(self.0.into_bytes())
+    }
+
+    /// Gives a read-only reference to the inner string.
+    #[inline]
+    pub const fn get_string(&self) -> &String {
+        &self.0
+    }
+
+    // Getting a mutable reference to the inner string is not
+    // allowed since it may be modified to become empty
+
+    /// Gets the string slice pointed to by the string.
+    /// This type implements `Deref<Target = NonEmptyStr>`, consider simply borrowing the value.
+    #[inline]
+    pub fn as_str(&self) -> &NonEmptyStr {
+        self
+    }
+
+    /// Gets the mutable string slice pointed to by the string.
+    /// This type implements `DerefMut<Target = NonEmptyStr>`, consider simply borrowing the value.
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut NonEmptyStr {
+        self
+    }
+
+    /// Returns the capacity of the string in bytes, which is guaranteed not to be 0.
+    #[inline]
+    pub fn capacity(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.capacity()) }
+    }
+
+    /// Wrapper around `String::reserve`, reimplemented since a direct mutable reference cannot be given to the underlying string.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional)
+    }
+
+    /// Wrapper around `String::reserve_exact`, reimplemented since a direct mutable reference cannot be given to the underlying string.
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional)
+    }
+
+    /// Wrapper around `String::shrink_to_fit`, reimplemented since a direct mutable reference cannot be given to the underlying string.
+    /// This only affects the string's capacity, and as such is safe to use.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    /// Wrapper around `String::push`, reimplemented since a direct mutable reference cannot be given to the underlying string.
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        self.0.push(ch)
+    }
+
+    /// Wrapper around `String::push_str`, reimplemented since a direct mutable reference cannot be given to the underlying string.\
+    /// Pushing an empty string slice leaves the string unchanged, so this method is safe to use.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.0.push_str(string)
+    }
+
+    /// Wrapper around `String::insert`, reimplemented since a direct mutable reference cannot be given to the underlying string.
+    #[inline]
+    pub fn insert(&mut self, index: usize, ch: char) {
+        self.0.insert(index, ch)
+    }
+
+    /// Wrapper around `String::insert_str`, reimplemented since a direct mutable reference cannot be given to the underlying string.
+    #[inline]
+    pub fn insert_str(&mut self, index: usize, string: &str) {
+        self.0.insert_str(index, string)
+    }
+
+    /// Safe wrapper around `String::pop`.\
+    /// Returns `None` and does not pop the character if this would cause the string to become empty.
+    #[inline]
+    pub fn try_pop(&mut self) -> Option<char> {
+        if self.get_len() == self.last_char().len_utf8() {
+            None
+        } else {
+            self.0.pop()
+        }
+    }
+
+    /// Safe wrapper around `String::truncate`.\
+    /// Returns `false` and does not modify the string if `new_len` is 0, or if it does not lie on a char boundary.
+    /// Otherwise, the string is shortened to `new_len` bytes (if it's longer than that) and `true` is returned.
+    #[inline]
+    pub fn try_truncate(&mut self, new_len: usize) -> bool {
+        if new_len == 0 || !self.0.is_char_boundary(new_len.min(self.get_len())) {
+            false
+        } else {
+            self.0.truncate(new_len);
+            true
+        }
+    }
+
+    /// Safe wrapper around `String::remove`.\
+    /// Returns `None` if this would cause the string to become empty.
+    #[inline]
+    pub fn try_remove(&mut self, index: usize) -> Option<char> {
+        if self.get_len() == self.first_char().len_utf8() {
+            None
+        } else {
+            Some(self.0.remove(index))
+        }
+    }
+}
+
+impl core::ops::Deref for NonEmptyString {
+    type Target = NonEmptyStr;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { NonEmptyStr::from_str_unchecked(&self.0) }
+    }
+}
+
+impl core::ops::DerefMut for NonEmptyString {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { NonEmptyStr::from_str_unchecked_mut(&mut self.0) }
+    }
+}
+
+impl core::fmt::Debug for NonEmptyString {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl core::fmt::Display for NonEmptyString {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Extend<char> for NonEmptyString {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a> Extend<&'a str> for NonEmptyString {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<NonEmptyStr> for NonEmptyString {
+    #[inline]
+    fn as_ref(&self) -> &NonEmptyStr {
+        self
+    }
+}
+
+impl AsRef<[u8]> for NonEmptyString {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl core::borrow::Borrow<NonEmptyStr> for NonEmptyString {
+    #[inline]
+    fn borrow(&self) -> &NonEmptyStr {
+        self
+    }
+}
+
+// AsMut<str> is fine to implement, since `&mut str` cannot change its length.
+
+impl AsMut<str> for NonEmptyString {
+    #[inline]
+    fn as_mut(&mut self) -> &mut str {
+        &mut self.0
+    }
+}
+
+
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = EmptyError<String>;
+
+    #[inline]
+    fn try_from(string: String) -> Result<NonEmptyString, EmptyError<String>> {
+        NonEmptyString::try_from_string(string)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NonEmptyString {
+    type Error = EmptyError<&'a str>;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<NonEmptyString, EmptyError<&'a str>> {
+        NonEmptyStr::try_from_str(s).map(ToOwned::to_owned)
+    }
+}
+
+impl TryFrom<NonEmptyVec<u8>> for NonEmptyString {
+    type Error = alloc::string::FromUtf8Error;
+
+    /// Checks that the bytes are valid UTF-8. They are already known not to be empty.
+    #[inline]
+    fn try_from(vec: NonEmptyVec<u8>) -> Result<NonEmptyString, alloc::string::FromUtf8Error> {
+        NonEmptyString::from_utf8(vec)
+    }
+}
+
+impl From<&NonEmptyStr> for NonEmptyString {
+    #[inline]
+    fn from(s: &NonEmptyStr) -> NonEmptyString {
+        s.to_owned()
+    }
+}
+
+impl From<char> for NonEmptyString {
+    #[inline]
+    fn from(ch: char) -> NonEmptyString {
+        NonEmptyString::new(ch)
+    }
+}
+
+impl From<NonEmptyString> for String {
+    #[inline]
+    fn from(s: NonEmptyString) -> String {
+        s.0
+    }
+}
+
+impl From<NonEmptyString> for NonEmptyVec<u8> {
+    #[inline]
+    fn from(s: NonEmptyString) -> NonEmptyVec<u8> {
+        s.into_bytes()
+    }
+}
+
+impl PartialEq<str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
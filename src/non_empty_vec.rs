@@ -1,6 +1,10 @@
-use std::num::NonZeroUsize;
+use core::num::NonZeroUsize;
+use alloc::{boxed::Box, vec::Vec};
 
+use crate::empty_error::*;
+use crate::non_empty_iter::*;
 use crate::non_empty_slice::*;
+use crate::non_empty_str::*;
 
 /// The easiest way to create a non-empty vec.
 /// An error will be raised if no elements are porvided.
@@ -8,14 +12,19 @@ use crate::non_empty_slice::*;
 /// 
 /// # Examples
 /// ```
+/// # use non_empty_continuous::*;
 /// let non_empty_vec_from_macro = ne_vec![99, 98, 97];
-/// let non_empty_vec_from_macro2 = ne_vec![0; std::num::NonZeroUsize::new(100).unwrap()];
-/// let _ = ne_vec![]; // Error: Cannot make an empty NonEmptyVec
+/// let non_empty_vec_from_macro2 = ne_vec![0; core::num::NonZeroUsize::new(100).unwrap()];
+/// ```
+/// 
+/// ```compile_fail
+/// # use non_empty_continuous::*;
+/// let _: NonEmptyVec<i32> = ne_vec![]; // Error: Cannot make an empty NonEmptyVec
 /// ```
 #[macro_export]
 macro_rules! ne_vec {
     ($($item: expr),+ $(,)?) => {
-        unsafe { $crate::NonEmptySmallVec::from_array_unchecked([$($item),+]) }
+        unsafe { $crate::NonEmptyVec::from_array_unchecked([$($item),+]) }
     };
     ($item: expr; $amount: expr) => {
         $crate::NonEmptyVec::from_elem($item, $amount)
@@ -39,7 +48,7 @@ impl<T> NonEmptyVec<T> {
     /// `NonEmptyVec::with_capacity`, `NonEmptyVec::from(array)`, or the `ne_vec!` macro.
     #[inline]
     pub fn new(item: T) -> NonEmptyVec<T> {
-        NonEmptyVec(vec![item])
+        NonEmptyVec(alloc::vec![item])
     }
 
     /// Creates a new NonEmptyVec, with precisely one element inside of it, and a 
@@ -63,32 +72,59 @@ impl<T> NonEmptyVec<T> {
     }
 
     /// Safely turns a `Vec` into a `NonEmptyVec` if the vector is not empty, 
-    /// otherwise an `Err` containing the original vector is returned.
+    /// otherwise an `EmptyError` containing the original vector is returned.
     /// 
     /// # Examples
     /// 
     /// ```
+    /// # use non_empty_continuous::*;
     /// let non_empty_vec: Vec<i32> = vec![1, 2, 3];
-    /// assert_eq!(NonEmptyVec::try_from_vec(non_empty_vec), Ok(NonEmptyVec::from_arr([1, 2, 3]));
+    /// assert_eq!(NonEmptyVec::try_from_vec(non_empty_vec), Ok(NonEmptyVec::from_arr([1, 2, 3])));
     /// 
     /// let empty_vec: Vec<i32> = vec![];
-    /// assert_eq!(NonEmptyVec::try_from_vec(empty_vec), Err(vec![]));
+    /// assert_eq!(NonEmptyVec::try_from_vec(empty_vec), Err(EmptyError::new(vec![])));
     /// ```
     #[inline]
-    pub fn try_from_vec(vec: Vec<T>) -> Result<NonEmptyVec<T>, Vec<T>> {
-        if vec.is_empty() { Err(vec) }
+    pub fn try_from_vec(vec: Vec<T>) -> Result<NonEmptyVec<T>, EmptyError<Vec<T>>> {
+        if vec.is_empty() { Err(EmptyError(vec)) }
         else { Ok(NonEmptyVec(vec)) }
     }
 
-    /// If `vec` is empty, the vector is not modified and `None` is returned. 
+    /// Collects `iter` into a `NonEmptyVec`, returning an `EmptyError` if it yields no items.\
+    /// If the iterator is known to be non-empty, use `NonEmptyIterator::collect` instead.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// assert_eq!(NonEmptyVec::try_from_iter(1..4), Ok(ne_vec![1, 2, 3]));
+    /// assert!(NonEmptyVec::try_from_iter(1..1).is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<NonEmptyVec<T>, EmptyError<()>> {
+        NonEmptyVec::try_from_vec(iter.into_iter().collect()).map_err(|_| EmptyError(()))
+    }
+
+    /// Creates a `NonEmptyVec` whose first element is `first`, followed by the items of `rest`.\
+    /// The lower bound of `rest`'s size hint is reserved upfront, so exact-size iterators only allocate once.
+    #[inline]
+    pub fn from_first_and_iter<I: IntoIterator<Item = T>>(first: T, rest: I) -> NonEmptyVec<T> {
+        let rest = rest.into_iter();
+        let mut vec = Vec::with_capacity(rest.size_hint().0.saturating_add(1));
+        vec.push(first);
+        vec.extend(rest);
+        NonEmptyVec(vec)
+    }
+
+    /// If `vec` is empty, the vector is not modified and an `EmptyError` containing the reference is returned. 
     /// Otherwise, `vec`'s items are moved to the new `NonEmptyVec` and `vec` is emptied.
     #[inline]
-    pub fn try_from_vec_ref_mut(vec: &mut Vec<T>) -> Option<NonEmptyVec<T>> {
-        if vec.is_empty() { None }
+    pub fn try_from_vec_ref_mut(vec: &mut Vec<T>) -> Result<NonEmptyVec<T>, EmptyError<&mut Vec<T>>> {
+        if vec.is_empty() { Err(EmptyError(vec)) }
         else {
             let mut non_empty_vec = NonEmptyVec(Vec::new());
-            std::mem::swap(vec, &mut non_empty_vec.0);
-            Some(non_empty_vec)
+            core::mem::swap(vec, &mut non_empty_vec.0);
+            Ok(non_empty_vec)
         }
     }
 
@@ -124,264 +160,479 @@ impl<T> NonEmptyVec<T> {
         self.0
     }
 
-    /// Gives a read-only reference to the inner vector.
+    /// Creates a `NonEmptyVec` from its first element and the rest of the elements, reusing `tail`'s allocation.\
+    /// This shifts all elements of `tail`, use `from_init_last` if the order allows for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let non_empty_vec = NonEmptyVec::from_head_tail(1, vec![2, 3]);
+    /// assert_eq!(non_empty_vec.into_head_tail(), (1, vec![2, 3]));
+    ///
+    /// let non_empty_vec = NonEmptyVec::from_init_last(vec![1, 2], 3);
+    /// assert_eq!(non_empty_vec.into_init_last(), (vec![1, 2], 3));
+    /// ```
     #[inline]
-    pub const fn get_vec(&self) -> &Vec<T> {
-        &self.0
+    pub fn from_head_tail(head: T, mut tail: Vec<T>) -> NonEmptyVec<T> {
+        tail.insert(0, head);
+        NonEmptyVec(tail)
     }
 
-    // Getting a mutable reference to the inner vec is not 
-    // allowed since it may be modified to become empty
-
-    /// Returns the capacity of the vector, which is guaranteed not to be 0.
+    /// Creates a `NonEmptyVec` from all elements but the last one and the last element, reusing `init`'s allocation.
     #[inline]
-    pub fn capacity(&self) -> NonZeroUsize {
-        unsafe { NonZeroUsize::new_unchecked(self.0.capacity()) }
+    pub fn from_init_last(mut init: Vec<T>, last: T) -> NonEmptyVec<T> {
+        init.push(last);
+        NonEmptyVec(init)
     }
 
-    // `additional` does not need to be non-zero (goes for all subcequent methods)
-
-    /// Wrapper around `Vec::reserve`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+    /// Splits the vector into its first element and the rest of the elements, reusing the allocation for the latter.\
+    /// This shifts all remaining elements, use `into_init_last` if the order allows for it.
     #[inline]
-    pub fn reserve(&mut self, additional: usize) {
-        self.0.reserve(additional)
+    pub fn into_head_tail(mut self) -> (T, Vec<T>) {
+        let head = self.0.remove(0);
+        (head, self.0)
     }
 
-    /// Wrapper around `Vec::reserve_exact`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+    /// Splits the vector into all elements but the last one and the last element, reusing the allocation for the former.
     #[inline]
-    pub fn reserve_exact(&mut self, additional: usize) {
-        self.0.reserve_exact(additional)
+    pub fn into_init_last(mut self) -> (Vec<T>, T) {
+        let last = unsafe { self.0.pop().unwrap_unchecked() };
+        (self.0, last)
     }
 
-    /// Wrapper around `Vec::try_reserve`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+    /// Creates a consuming iterator, which is guaranteed to yield at least one item.
+    /// Consider using `into_iter` instead if the guarantee is not needed.
     #[inline]
-    pub fn try_reserve(&mut self, additional: usize)  -> Result<(), std::collections::TryReserveError> {
-        self.0.try_reserve(additional)
+    pub fn into_iter_ne(self) -> NonEmptyIter<alloc::vec::IntoIter<T>> {
+        NonEmptyIter(self.0.into_iter())
     }
 
-    /// Wrapper around `Vec::try_reserve_exact`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+    /// Maps every element of the vector into a new `NonEmptyVec`.\
+    /// The standard library may reuse the allocation when `T` and `U` have the same size and alignment,
+    /// but this is an optimization that is not guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let non_empty_vec = ne_vec![1u32, 2, 3];
+    /// assert_eq!(non_empty_vec.map(|n| n as f32 / 2.0), ne_vec![0.5, 1.0, 1.5]);
+    /// ```
     #[inline]
-    pub fn try_reserve_exact(&mut self, additional: usize)  -> Result<(), std::collections::TryReserveError> {
-        self.0.try_reserve_exact(additional)
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> NonEmptyVec<U> {
+        NonEmptyVec(self.0.into_iter().map(f).collect())
     }
 
-    /// Wrapper around `Vec::shrink_to_fit`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
-    /// This only affects the vector's capacity, and as such is safe to use.
+    /// Maps every element of the vector with a fallible function, stopping at the first error.\
+    /// Like `map`, the allocation may be reused, but this is not guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// assert_eq!(ne_vec!["1", "2"].try_map(str::parse::<i32>), Ok(ne_vec![1, 2]));
+    /// assert!(ne_vec!["1", "x"].try_map(str::parse::<i32>).is_err());
+    /// ```
     #[inline]
-    pub fn shrink_to_fit(&mut self) {
-        self.0.shrink_to_fit()
+    pub fn try_map<U, E, F: FnMut(T) -> Result<U, E>>(self, f: F) -> Result<NonEmptyVec<U>, E> {
+        self.0.into_iter().map(f).collect::<Result<Vec<U>, E>>().map(NonEmptyVec)
     }
 
-    /// Wrapper around `Vec::shrink_to`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
-    /// This only affects the vector's capacity, and as such is safe to use.
+    /// Keeps the `k` smallest elements of the vector, sorted from smallest to largest, reusing its allocation.\
+    /// If `k` is larger than the length of the vector, every element is kept.
+    /// The vector is only partially sorted, using `select_nth_unstable`, so this runs in `O(n + k * log(k))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// # use core::num::NonZeroUsize;
+    /// let scores = ne_vec![30, 10, 50, 20, 40];
+    /// assert_eq!(scores.into_k_largest(NonZeroUsize::new(2).unwrap()), ne_vec![50, 40]);
+    /// ```
     #[inline]
-    pub fn shrink_to(&mut self, min_capacity: usize) {
-        self.0.shrink_to(min_capacity)
+    pub fn into_k_smallest(self, k: NonZeroUsize) -> NonEmptyVec<T> where T: Ord {
+        self.into_k_smallest_by(k, T::cmp)
     }
 
-    /// Converts the vector into `Box<NonEmptySlice<T>>`, preserving non-emptyness guarantees.
-    #[inline]
-    pub fn into_boxed_slice(self) -> Box<NonEmptySlice<T>> {
-        unsafe { 
-            std::mem::transmute::<
-                Box<[T]>, 
-                Box<NonEmptySlice<T>>
-            >(self.0.into_boxed_slice()) 
+    /// Keeps the `k` smallest elements of the vector with respect to `compare`, sorted from smallest to largest, reusing its allocation.\
+    /// If `k` is larger than the length of the vector, every element is kept.
+    pub fn into_k_smallest_by<F>(mut self, k: NonZeroUsize, mut compare: F) -> NonEmptyVec<T> where F: FnMut(&T, &T) -> core::cmp::Ordering {
+        if k.get() < self.0.len() {
+            self.0.select_nth_unstable_by(k.get() - 1, &mut compare);
+            self.0.truncate(k.get());
         }
+        self.0.sort_unstable_by(compare);
+        self
     }
 
-    /// Wrapper around `Vec::truncate`. If `len` were 0, that would cause the vector to become empty.
+    /// Keeps the `k` elements of the vector that give the smallest values of `f`, sorted from smallest to largest, reusing its allocation.\
+    /// If `k` is larger than the length of the vector, every element is kept.
     #[inline]
-    pub fn truncate(&mut self, len: NonZeroUsize) {
-        self.0.truncate(len.get())
+    pub fn into_k_smallest_by_key<K: Ord, F>(self, k: NonZeroUsize, mut f: F) -> NonEmptyVec<T> where F: FnMut(&T) -> K {
+        self.into_k_smallest_by(k, |a, b| f(a).cmp(&f(b)))
     }
 
-    /// Gets the underlying slice pointed to by the vector.
-    /// This type implements `Deref<Target = NonEmptySlice<T>`, consider simply borrowing the value.
+    /// Keeps the `k` largest elements of the vector, sorted from largest to smallest, reusing its allocation.\
+    /// If `k` is larger than the length of the vector, every element is kept.
     #[inline]
-    pub fn as_slice(&self) -> &NonEmptySlice<T> {
-        self
+    pub fn into_k_largest(self, k: NonZeroUsize) -> NonEmptyVec<T> where T: Ord {
+        self.into_k_smallest_by(k, |a, b| b.cmp(a))
     }
 
-    /// Gets the underlying slice pointed to by the vector.
-    /// This type implements `DerefMut<Target = NonEmptySlice<T>`, consider simply borrowing the value.
+    /// Keeps the `k` largest elements of the vector with respect to `compare`, sorted from largest to smallest, reusing its allocation.\
+    /// If `k` is larger than the length of the vector, every element is kept.
     #[inline]
-    pub fn as_slice_mut(&mut self) -> &mut NonEmptySlice<T> {
-        self
+    pub fn into_k_largest_by<F>(self, k: NonZeroUsize, mut compare: F) -> NonEmptyVec<T> where F: FnMut(&T, &T) -> core::cmp::Ordering {
+        self.into_k_smallest_by(k, |a, b| compare(b, a))
     }
 
-    /// Wrapper around `Vec::set_len`.
-    /// # Safety
-    /// This comes with the same requirements as `Vec::set_len`.
+    /// Keeps the `k` elements of the vector that give the largest values of `f`, sorted from largest to smallest, reusing its allocation.\
+    /// If `k` is larger than the length of the vector, every element is kept.
     #[inline]
-    pub unsafe fn set_len(&mut self, new_len: NonZeroUsize) {
-        self.0.set_len(new_len.get())
+    pub fn into_k_largest_by_key<K: Ord, F>(self, k: NonZeroUsize, mut f: F) -> NonEmptyVec<T> where F: FnMut(&T) -> K {
+        self.into_k_smallest_by(k, |a, b| f(b).cmp(&f(a)))
     }
 
-    // `Vec::retain` cannot be implemented since the function may retain no items.
-
-    /// Wrapper around `Vec::dedup_by_key`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
-    /// Dedup cannot leave the vector empty so this method is safe to use.
+    /// Gives a read-only reference to the inner vector.
     #[inline]
-    pub fn dedup_by_key<F, K>(&mut self, key: F) where F: FnMut(&mut T) -> K, K: PartialEq {
-        self.0.dedup_by_key(key)
+    pub const fn get_vec(&self) -> &Vec<T> {
+        &self.0
     }
 
-    /// Wrapper around `Vec::dedup_by`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
-    /// Dedup cannot leave the vector empty so this method is safe to use.
+    // Getting a mutable reference to the inner vec is not 
+    // allowed since it may be modified to become empty
+
+    /// Converts the vector into `Box<NonEmptySlice<T>>`, preserving non-emptyness guarantees.
     #[inline]
-    pub fn dedup_by<F>(&mut self, same_bucket: F) where F: FnMut(&mut T, &mut T) -> bool {
-        self.0.dedup_by(same_bucket)
+    pub fn into_boxed_slice(self) -> Box<NonEmptySlice<T>> {
+        unsafe { 
+            core::mem::transmute::<
+                Box<[T]>, 
+                Box<NonEmptySlice<T>>
+            >(self.0.into_boxed_slice()) 
+        }
     }
 
-    /// Wrapper around `Vec::push`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
-    /// Pushes an element to the end of the vector, reallocatig if needed.
+    vec_wrapper_methods!(vec: Vec<T>, drain: alloc::vec::Drain<'_, T>, reserve_error: alloc::collections::TryReserveError);
+
+    /// Safe counterpart of `Vec::extract_if`.\
+    /// Removes and returns the elements in `range` for which `filter` returns `true`, in order,
+    /// but stops extracting once one element is left in the vector. `filter` is not called on any element after that.
+    ///
+    /// Unlike `Vec::extract_if`, the elements are extracted eagerly. A lazy iterator could be leaked,
+    /// and `Vec::extract_if` leaves the vector empty until its iterator is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let mut non_empty_vec = ne_vec![1, 2, 3, 4, 5, 6];
+    /// let evens: Vec<i32> = non_empty_vec.extract_if(.., |x| *x % 2 == 0).collect();
+    /// assert_eq!((evens, &non_empty_vec), (vec![2, 4, 6], &ne_vec![1, 3, 5]));
+    ///
+    /// let extracted: Vec<i32> = non_empty_vec.extract_if(.., |_| true).collect();
+    /// assert_eq!((extracted, &non_empty_vec), (vec![1, 3], &ne_vec![5]));
+    /// ```
     #[inline]
-    pub fn push(&mut self, value: T) {
-        self.0.push(value)
+    pub fn extract_if<R, F>(&mut self, range: R, mut filter: F) -> alloc::vec::IntoIter<T>
+    where R: core::ops::RangeBounds<usize>, F: FnMut(&mut T) -> bool {
+        let mut remaining = self.get_len();
+        let extracted: Vec<T> = self.0.extract_if(range, |item| {
+            let extract = remaining > 1 && filter(item);
+            remaining -= extract as usize;
+            extract
+        }).collect();
+        extracted.into_iter()
     }
 
-    /// Wrapper around `Vec::insert`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
-    /// Inserts the element at the given index, shifting items as needed.
+    /// Unsafe wrapper around `Vec::extract_if`.\
+    /// For a safe version of this method, use `NonEmptyVec::extract_if`.
+    /// # Safety
+    /// `filter` must not extract every element of the vector.
+    /// The returned iterator must not be leaked, since the vector is left empty until it is dropped.
     #[inline]
-    pub fn insert(&mut self, index: usize, element: T) {
-        self.0.insert(index, element)
+    pub unsafe fn extract_if_unchecked<R, F>(&mut self, range: R, filter: F) -> alloc::vec::ExtractIf<'_, T, F>
+    where R: core::ops::RangeBounds<usize>, F: FnMut(&mut T) -> bool {
+        self.0.extract_if(range, filter)
     }
 
-    /// Wrapper around `Vec::append`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
-    /// This method empties `other`, meaning it cannot be a `NonEmptyVec`.
+    // `Vec::clear` cannot be implemented for obvious reasons.
+
+    // `Vec::len` won't be implemented since `NonEmptySlice` already 
+    // implements it, and `Self` implements `Deref<Target = NonEmptySlice>`
+
+    // const IS_EMPTY: bool = false;
+
+    /// If `at` was 0 all items of `self` would be moved into the new vec, leaving `self` empty.
+    /// The returned vector is empty if `at` equals the length of `self`,
+    /// use `try_split_off` or `split_off_last` to get a `NonEmptyVec` instead.
     #[inline]
-    pub fn append_vec(&mut self, other: &mut Vec<T>) {
-        self.0.append(other)
+    pub fn split_off(&mut self, at: NonZeroUsize) -> Vec<T> {
+        self.0.split_off(at.get())
     }
 
-    /// Safe wrapper around `Vec::pop`.\
-    /// Returns `None` and does not pop the element if this would cause the vector to become empty.
+    /// Splits the vector in two at `at`, returning the elements from `at` onwards as a `NonEmptyVec`.\
+    /// Both halves must be non-empty, meaning `at` must be between 1 and `self.len() - 1`.
+    /// Otherwise, including when `at` is out of bounds, an `EmptyError` is returned and `self` is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let mut non_empty_vec = ne_vec![1, 2, 3];
+    /// assert!(non_empty_vec.try_split_off(0).is_err());
+    /// assert!(non_empty_vec.try_split_off(3).is_err());
+    /// assert!(non_empty_vec.try_split_off(4).is_err());
+    /// assert_eq!(non_empty_vec.try_split_off(1), Ok(ne_vec![2, 3]));
+    /// assert_eq!(non_empty_vec, ne_vec![1]);
+    /// ```
     #[inline]
-    pub fn try_pop(&mut self) -> Option<T> {
-        if self.has_just_1_element() {
-            None
+    pub fn try_split_off(&mut self, at: usize) -> Result<NonEmptyVec<T>, EmptyError<()>> {
+        if at == 0 || at >= self.0.len() {
+            Err(EmptyError(()))
         } else {
-            self.0.pop()
+            Ok(NonEmptyVec(self.0.split_off(at)))
         }
     }
 
-    /// Wrapper around `Vec::swap_remove`.\
-    /// This method ensures that it won't cause the vector to become empty by not allowing the first element to be removed.
-    /// For a mehod that accepts any index, use `NonEmptyVec::try_swap_remove`.
+    /// Removes the last `n` elements of the vector and returns them as a `NonEmptyVec`.\
+    /// Returns `None` and leaves `self` untouched if `n` is not less than the length of the vector,
+    /// since `self` would become empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// # use core::num::NonZeroUsize;
+    /// let mut non_empty_vec = ne_vec![1, 2, 3];
+    /// assert_eq!(non_empty_vec.split_off_last(NonZeroUsize::new(2).unwrap()), Some(ne_vec![2, 3]));
+    /// assert_eq!(non_empty_vec.split_off_last(NonZeroUsize::MIN), None);
+    /// ```
     #[inline]
-    pub fn swap_remove(&mut self, index: NonZeroUsize) -> T {
-        self.0.swap_remove(index.get())
+    pub fn split_off_last(&mut self, n: NonZeroUsize) -> Option<NonEmptyVec<T>> {
+        self.try_split_off(self.0.len().checked_sub(n.get())?).ok()
     }
 
-    /// Safe wrapper around `Vec::swap_remove`.\
-    /// Returns `None` if this would cause the vector to become empty.
-    /// Otherwise, moves out the element at `index` and replaces it with the last element in the vector.
+    /// Splits the vector in two at `at`, returning both halves as `NonEmptyVec`s.\
+    /// The allocation of `self` is reused for the first half.
+    /// Fails in the same cases as `try_split_off`, in which case `self` is given back as the error, since it was moved in.
     #[inline]
-    pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
-        if self.has_just_1_element() {
-            None
-        } else {
-            Some(self.0.swap_remove(index))
+    pub fn split_at_owned(mut self, at: usize) -> Result<(NonEmptyVec<T>, NonEmptyVec<T>), NonEmptyVec<T>> {
+        match self.try_split_off(at) {
+            Ok(tail) => Ok((self, tail)),
+            Err(_) => Err(self),
         }
     }
 
-    /// Unsafe wrapper around `Vec::swap_remove`.\
-    /// For a safe version of this method, use `NonEmptyVec::try_swap_remove`.
-    /// # Safety
-    /// Running this must not cause the vector to become empty.
-    #[inline]
-    pub unsafe fn swap_remove_unchecked(&mut self, index: usize) -> T {
-        self.0.swap_remove(index)
-    }
-
-    /// Safe wrapper around `Vec::remove`.\
-    /// Returns `None` if this would cause the vector to become empty.
+    /// Wrapper around `Vec::leak`, that preserves non-emptyness guarantees.
     #[inline]
-    pub fn try_remove(&mut self, index: usize) -> Option<T> {
-        if self.has_just_1_element(){
-            None
-        } else {
-            Some(self.0.remove(index))
-        }
+    pub fn leak<'a>(self) -> &'a mut NonEmptySlice<T> {
+        unsafe { NonEmptySlice::from_slice_unchecked_mut(self.0.leak()) }
     }
 
-    /// Unsafe wrapper around `Vec::remove`.\
-    /// For a safe version of this method, use `NonEmptyVec::try_remove`.
-    /// # Safety
-    /// Running this must not cause the vector to become empty.
+    /// Wrapper around `Vec::spare_capacity_mut`.\
+    /// The spare capacity may be empty, so a plain slice is returned.
+    /// After writing to it, use `set_len` to mark the elements as initialized.
     #[inline]
-    pub unsafe fn remove_unchecked(&mut self, index: usize) -> T {
-        self.0.remove(index)
+    pub fn spare_capacity_mut(&mut self) -> &mut [core::mem::MaybeUninit<T>] {
+        self.0.spare_capacity_mut()
     }
 
-    /// Safe wrapper around  `Vec::drain`.\
-    /// This method returns `None` and does not remove any elements if it coveres the whole vector.
+    /// Safe counterpart of `Vec::splice`.\
+    /// This method returns `None` and does not modify the vector if `range` covers the whole vector and `replace_with` is empty.
+    /// In that case, `replace_with` is advanced once to check whether it is empty.
+    ///
+    /// Unlike `Vec::splice`, the elements are replaced eagerly, and the removed ones are returned as an owned iterator.
+    /// A lazy `Splice` could be leaked, and `Vec::splice` leaves the vector truncated until it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let mut non_empty_vec = ne_vec![1, 2, 3];
+    /// let removed: Vec<i32> = non_empty_vec.splice(..2, [7, 8, 9]).unwrap().collect();
+    /// assert_eq!((removed, &non_empty_vec), (vec![1, 2], &ne_vec![7, 8, 9, 3]));
+    ///
+    /// assert!(non_empty_vec.splice(.., []).is_none());
+    /// assert_eq!(non_empty_vec, ne_vec![7, 8, 9, 3]);
+    /// ```
     #[inline]
-    pub fn drain<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> Option<std::vec::Drain<'_, T>> {
-        if range.contains(&0) && range.contains(&(self.len().get() - 1)) {
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Option<alloc::vec::IntoIter<T>>
+    where R: core::ops::RangeBounds<usize>, I: IntoIterator<Item = T> {
+        let mut replace_with = replace_with.into_iter().peekable();
+        if range.contains(&0) && range.contains(&(self.get_len() - 1)) && replace_with.peek().is_none() {
             None
         } else {
-            Some(self.0.drain(range))
+            Some(self.0.splice(range, replace_with).collect::<Vec<T>>().into_iter())
         }
     }
 
-    /// Unsafe wrapper around `Vec::drain.`\
-    /// For a safe version of this method, use `NonEmptyVec::drain`.
+    /// Unsafe wrapper around `Vec::splice`.\
+    /// For a safe version of this method, use `NonEmptyVec::splice`.
     /// # Safety
-    /// `range` must not take up the entire vector.
+    /// If `range` takes up the entire vector, `replace_with` must not be empty.
+    /// The returned `Splice` must not be leaked, since the vector is left truncated until it is dropped.
     #[inline]
-    pub unsafe fn drain_unchecked<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> std::vec::Drain<'_, T> {
-        self.0.drain(range)
+    pub unsafe fn splice_unchecked<R, I>(&mut self, range: R, replace_with: I) -> alloc::vec::Splice<'_, I::IntoIter>
+    where R: core::ops::RangeBounds<usize>, I: IntoIterator<Item = T> {
+        self.0.splice(range, replace_with)
     }
 
-    // `Vec::clear` cannot be implemented for obvious reasons.
-
-    // `Vec::len` won't be implemented since `NonEmptySlice` already 
-    // implements it, and `Self` implements `Deref<Target = NonEmptySlice>`
-
-    // const IS_EMPTY: bool = false;
-
-    /// If `at` was 0 all items of `self` would be moved into the new vec, leaving `self` empty.
-    /// This cannot return NonZeroVec since that would require at to be at least 2,
-    /// and at that point you might as well do `NonZeroUsize::try_new(self.split_off(at))`
+    /// Gives mutable access to the inner vector through a `VecGuard`, which is checked not to be empty once it's done.\
+    /// The vector is replaced by `[fallback]` for as long as the guard lives, which is also what remains
+    /// if the edited vector ends up empty. Otherwise, the edits are kept and `fallback` is dropped.\
+    /// Unlike `edit`, this method doesn't need to clone the vector, but the previous state cannot be restored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let mut non_empty_vec = ne_vec![1, 2, 3, 4];
+    /// non_empty_vec.edit_or(0).retain(|x| x % 2 == 0);
+    /// assert_eq!(non_empty_vec, ne_vec![2, 4]);
+    ///
+    /// non_empty_vec.edit_or(0).clear();
+    /// assert_eq!(non_empty_vec, ne_vec![0]);
+    /// ```
     #[inline]
-    pub fn split_off(&mut self, at: NonZeroUsize) -> Vec<T> {
-        self.0.split_off(at.get())
+    pub fn edit_or(&mut self, fallback: T) -> VecGuard<'_, T> {
+        let vec = core::mem::replace(&mut self.0, alloc::vec![fallback]);
+        VecGuard { non_empty_vec: self, vec }
     }
+}
 
-    /// Safe wrapper around `Vec::resize_with`, that ensures the vector cannot become empty.
+impl<T> NonEmptyVec<NonEmptyVec<T>> {
+    /// Flattens a vector of `NonEmptyVec`s into a single vector, moving every element.\
+    /// The result is guaranteed not to be empty, and is allocated once with the summed length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let nested = ne_vec![ne_vec![1, 2], ne_vec![3]];
+    /// assert_eq!(nested.flatten(), ne_vec![1, 2, 3]);
+    /// ```
     #[inline]
-    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: NonZeroUsize, f: F) {
-        self.0.resize_with(new_len.get(), f)
+    pub fn flatten(self) -> NonEmptyVec<T> {
+        let mut vec = Vec::with_capacity(self.0.iter().map(|v| v.0.len()).sum());
+        for v in self.0 {
+            vec.extend(v.0);
+        }
+        NonEmptyVec(vec)
     }
+}
 
-    /// Wrapper around `Vec::leak`, that preserves non-emptyness guarantees.
+impl<T> NonEmptyVec<Option<T>> {
+    /// Turns a vector of `Option`s into `Some` vector if every element is `Some`, or `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// assert_eq!(ne_vec![Some(1), Some(2)].transpose(), Some(ne_vec![1, 2]));
+    /// assert_eq!(ne_vec![Some(1), None].transpose(), None);
+    /// ```
     #[inline]
-    pub fn leak<'a>(self) -> &'a mut NonEmptySlice<T> {
-        unsafe { NonEmptySlice::from_slice_unchecked_mut(self.0.leak()) }
+    pub fn transpose(self) -> Option<NonEmptyVec<T>> {
+        self.0.into_iter().collect::<Option<Vec<T>>>().map(NonEmptyVec)
     }
+}
 
-    /// Wrapper around `Vec::new_unchecked_mut`, that preserves non-emptyness guarantees.
+impl<T, E> NonEmptyVec<Result<T, E>> {
+    /// Turns a vector of `Result`s into an `Ok` vector if every element is `Ok`, or the first error otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let results: NonEmptyVec<Result<i32, &str>> = ne_vec![Ok(1), Err("a"), Err("b")];
+    /// assert_eq!(results.transpose(), Err("a"));
+    /// ```
     #[inline]
-    pub fn spare_capacity_mut(&mut self) -> &mut NonEmptySlice<std::mem::MaybeUninit<T>> {
-        unsafe { NonEmptySlice::from_slice_unchecked_mut(self.0.spare_capacity_mut()) }
+    pub fn transpose(self) -> Result<NonEmptyVec<T>, E> {
+        self.0.into_iter().collect::<Result<Vec<T>, E>>().map(NonEmptyVec)
     }
 
-    /// Wrapper around `Vec::splice`.
+    /// Turns a vector of `Result`s into an `Ok` vector if every element is `Ok`, or all of the errors otherwise.\
+    /// Since there is at least one error when this returns `Err`, the errors are gathered in a `NonEmptyVec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let results: NonEmptyVec<Result<i32, &str>> = ne_vec![Ok(1), Err("a"), Err("b")];
+    /// assert_eq!(results.collect_all_errors(), Err(ne_vec!["a", "b"]));
+    /// ```
+    pub fn collect_all_errors(self) -> Result<NonEmptyVec<T>, NonEmptyVec<E>> {
+        let mut oks = Vec::with_capacity(self.0.len());
+        let mut errors = Vec::new();
+        for result in self.0 {
+            match result {
+                Ok(ok) if errors.is_empty() => oks.push(ok),
+                Ok(_) => {}
+                Err(error) => errors.push(error),
+            }
+        }
+        if errors.is_empty() {
+            Ok(NonEmptyVec(oks))
+        } else {
+            Err(NonEmptyVec(errors))
+        }
+    }
+}
+
+impl<A, B> NonEmptyVec<(A, B)> {
+    /// Splits a vector of pairs into two vectors, which are both guaranteed not to be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let pairs = ne_vec![("id", 1), ("name", 2)];
+    /// assert_eq!(pairs.unzip(), (ne_vec!["id", "name"], ne_vec![1, 2]));
+    /// ```
     #[inline]
-    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> std::vec::Splice<'_, I::IntoIter>
-    where R: std::ops::RangeBounds<usize>, I: IntoIterator<Item = T> {
-        self.0.splice(range, replace_with)
+    pub fn unzip(self) -> (NonEmptyVec<A>, NonEmptyVec<B>) {
+        let (a, b) = self.0.into_iter().unzip();
+        (NonEmptyVec(a), NonEmptyVec(b))
     }
 }
 
 impl<T: Clone> NonEmptyVec<T> {
+    /// Gives mutable access to a clone of the inner vector through a `VecGuard`.\
+    /// The edits are applied by `VecGuard::commit` or when the guard is dropped, unless the edited vector is empty,
+    /// in which case the vector keeps its previous state.
+    /// Use `edit_or` to avoid cloning the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let mut non_empty_vec = ne_vec![3, 1, 2];
+    /// let mut guard = non_empty_vec.edit();
+    /// guard.sort_unstable();
+    /// guard.truncate(2);
+    /// assert!(guard.commit().is_ok());
+    /// assert_eq!(non_empty_vec, ne_vec![1, 2]);
+    ///
+    /// let mut guard = non_empty_vec.edit();
+    /// guard.retain(|x| *x > 5);
+    /// assert!(guard.commit().is_err());
+    /// assert_eq!(non_empty_vec, ne_vec![1, 2]);
+    /// ```
+    #[inline]
+    pub fn edit(&mut self) -> VecGuard<'_, T> {
+        let vec = self.0.clone();
+        VecGuard { non_empty_vec: self, vec }
+    }
+
     /// Creates a vector from `elem`, copied `n` times.\
     /// Mostly for use in the ne_vec![elem; n] macro.
     #[inline]
     pub fn from_elem(elem: T, n: NonZeroUsize) -> NonEmptyVec<T> {
-        NonEmptyVec(std::vec::from_elem(elem, n.get()))
+        NonEmptyVec(alloc::vec![elem; n.get()])
     }
 
     /// Safe wrapper around `Vec::new_unchecked_mut`, that ensures the vector cannot become empty.
@@ -398,7 +649,7 @@ impl<T: Clone> NonEmptyVec<T> {
 
     /// Wrapper around `Vec::extend_from_within`.
     #[inline]
-    pub fn extend_from_within<R: std::ops::RangeBounds<usize>>(&mut self, src: R) {
+    pub fn extend_from_within<R: core::ops::RangeBounds<usize>>(&mut self, src: R) {
         self.0.extend_from_within(src)
     }
 }
@@ -411,7 +662,68 @@ impl<T: PartialEq> NonEmptyVec<T> {
     }
 }
 
-impl<T> std::ops::Deref for NonEmptyVec<T> {
+/// Mutable access to the vector behind a `NonEmptyVec`, created by `NonEmptyVec::edit` and `NonEmptyVec::edit_or`.
+///
+/// This type implements `DerefMut<Target = Vec<T>>`, so any `Vec` method can be used, including ones that may leave it empty.
+/// The vector is checked not to be empty when the guard is committed or dropped.
+/// Meanwhile, the `NonEmptyVec` is left in a valid state, so leaking the guard is safe.
+pub struct VecGuard<'a, T> {
+    non_empty_vec: &'a mut NonEmptyVec<T>,
+    vec: Vec<T>,
+}
+
+impl<T> VecGuard<'_, T> {
+    /// Applies the edits if the edited vector is not empty.
+    /// Otherwise, an `EmptyError` containing the empty vector is returned, and the `NonEmptyVec` is left as it was before editing
+    /// (or as `[fallback]` if the guard was created by `edit_or`).
+    #[inline]
+    pub fn commit(mut self) -> Result<(), EmptyError<Vec<T>>> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<(), EmptyError<Vec<T>>> {
+        let vec = core::mem::take(&mut self.vec);
+        if vec.is_empty() {
+            Err(EmptyError(vec))
+        } else {
+            self.non_empty_vec.0 = vec;
+            Ok(())
+        }
+    }
+}
+
+impl<T> core::ops::Deref for VecGuard<'_, T> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T> core::ops::DerefMut for VecGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for VecGuard<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.vec)
+    }
+}
+
+/// Same as `VecGuard::commit`, ignoring the result.
+impl<T> Drop for VecGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        _ = self.finish();
+    }
+}
+
+impl<T> core::ops::Deref for NonEmptyVec<T> {
     type Target = NonEmptySlice<T>;
 
     #[inline]
@@ -420,25 +732,33 @@ impl<T> std::ops::Deref for NonEmptyVec<T> {
     }
 }
 
-impl<T> std::ops::DerefMut for NonEmptyVec<T> {
+impl<T> core::ops::DerefMut for NonEmptyVec<T> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe { NonEmptySlice::<T>::from_slice_unchecked_mut(&mut self.0) }
     }
 }
 
-impl<T: std::fmt::Debug> std::fmt::Debug for NonEmptyVec<T> {
+impl<T: core::fmt::Debug> core::fmt::Debug for NonEmptyVec<T> {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.0)
     }
 }
 
-// Cannot implement `from_iter` since iterators may only have one item.
+// Cannot implement `FromIterator` since iterators may not have any items.
+// Use `NonEmptyVec::try_from_iter`, or `NonEmptyIterator::collect` instead.
+
+impl<T> FromNonEmptyIterator<T> for NonEmptyVec<T> {
+    #[inline]
+    fn from_non_empty_iter<I: NonEmptyIterator<Item = T>>(iter: I) -> NonEmptyVec<T> {
+        NonEmptyVec(iter.into_iter().collect())
+    }
+}
 
 impl<T> IntoIterator for NonEmptyVec<T> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
@@ -448,7 +768,7 @@ impl<T> IntoIterator for NonEmptyVec<T> {
 
 impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = core::slice::Iter<'a, T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
@@ -458,7 +778,7 @@ impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
 
 impl<'a, T> IntoIterator for &'a mut NonEmptyVec<T> {
     type Item = &'a mut T;
-    type IntoIter = std::slice::IterMut<'a, T>;
+    type IntoIter = core::slice::IterMut<'a, T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
@@ -545,56 +865,67 @@ impl<T: Clone> From<&mut NonEmptySlice<T>> for NonEmptyVec<T> {
 }
 
 impl<'a, T: Clone> TryFrom<&'a [T]> for NonEmptyVec<T> {
-    type Error = &'a [T];
+    type Error = EmptyError<&'a [T]>;
 
     #[inline]
-    fn try_from(s: &[T]) -> Result<NonEmptyVec<T>, &[T]> {
+    fn try_from(s: &'a [T]) -> Result<NonEmptyVec<T>, EmptyError<&'a [T]>> {
         NonEmptySlice::try_from_slice(s).map(|x| x.to_vec())
     }
 }
 
 impl<'a, T: Clone> TryFrom<&'a mut [T]> for NonEmptyVec<T> {
-    type Error = &'a mut [T];
+    type Error = EmptyError<&'a mut [T]>;
 
     #[inline]
-    fn try_from(s: &mut [T]) -> Result<NonEmptyVec<T>, &mut [T]> {
+    fn try_from(s: &'a mut [T]) -> Result<NonEmptyVec<T>, EmptyError<&'a mut [T]>> {
         NonEmptySlice::try_from_slice_mut(s).map(|x| x.to_vec())
     }
 }
 
-impl<'a, T: Clone> TryFrom<std::borrow::Cow<'a, [T]>> for NonEmptyVec<T> {
-    type Error = std::borrow::Cow<'a, [T]>;
+impl<'a, T: Clone> TryFrom<alloc::borrow::Cow<'a, [T]>> for NonEmptyVec<T> {
+    type Error = EmptyError<alloc::borrow::Cow<'a, [T]>>;
 
     #[inline]
-    fn try_from(s: std::borrow::Cow<'a, [T]>) -> Result<NonEmptyVec<T>, std::borrow::Cow<'a, [T]>> {
-        if s.is_empty() { Err(s) }
+    fn try_from(s: alloc::borrow::Cow<'a, [T]>) -> Result<NonEmptyVec<T>, EmptyError<alloc::borrow::Cow<'a, [T]>>> {
+        if s.is_empty() { Err(EmptyError(s)) }
         else { Ok(NonEmptyVec(s.to_vec())) }
     }
 }
 
 impl<T> TryFrom<Box<[T]>> for NonEmptyVec<T> {
-    type Error = Box<[T]>;
+    type Error = EmptyError<Box<[T]>>;
 
     #[inline]
-    fn try_from(s: Box<[T]>) -> Result<NonEmptyVec<T>, Box<[T]>> {
-        if s.is_empty() { Err(s) }
+    fn try_from(s: Box<[T]>) -> Result<NonEmptyVec<T>, EmptyError<Box<[T]>>> {
+        if s.is_empty() { Err(EmptyError(s)) }
         else { Ok(NonEmptyVec(s.into())) }
     }
 }
 
 impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
-    type Error = Vec<T>;
+    type Error = EmptyError<Vec<T>>;
 
     #[inline]
-    fn try_from(s: Vec<T>) -> Result<NonEmptyVec<T>, Vec<T>> {
+    fn try_from(s: Vec<T>) -> Result<NonEmptyVec<T>, EmptyError<Vec<T>>> {
         NonEmptyVec::try_from_vec(s)
     }
 }
 
-impl From<&str> for NonEmptyVec<u8> {
+// `From<&str>` cannot be implemented since the string slice may be empty.
+
+impl<'a> TryFrom<&'a str> for NonEmptyVec<u8> {
+    type Error = EmptyError<&'a str>;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<NonEmptyVec<u8>, EmptyError<&'a str>> {
+        NonEmptyStr::try_from_str(s).map(NonEmptyVec::from)
+    }
+}
+
+impl From<&NonEmptyStr> for NonEmptyVec<u8> {
     #[inline]
-    fn from(s: &str) -> NonEmptyVec<u8> {
-        NonEmptyVec(From::from(s.as_bytes()))
+    fn from(s: &NonEmptyStr) -> NonEmptyVec<u8> {
+        NonEmptyVec(From::from(s.as_bytes().get_slice()))
     }
 }
 
@@ -0,0 +1,402 @@
+use alloc::collections::VecDeque;
+use core::num::NonZeroUsize;
+
+use crate::empty_error::*;
+use crate::non_empty_iter::*;
+use crate::non_empty_slice::*;
+use crate::non_empty_vec::*;
+
+/// A non-empty double-ended queue, wrapping `VecDeque`.
+///
+/// Unlike the other types in this crate, this one is not continuous, so it does not dereference to `NonEmptySlice`.
+/// Use `make_contiguous` to get a `&mut NonEmptySlice` over the whole queue.
+///
+/// Getting direct mutable acces to the inner queue is not allowed,
+/// since that way setting the size of the queue to 0 becomes possible.
+/// As such, many methods that mutate the inner queue are re-implemented.
+///
+/// # Examples
+///
+/// ```
+/// # use non_empty_continuous::*;
+/// let mut queue = NonEmptyVecDeque::new(1);
+/// queue.push_back(2);
+/// queue.push_front(0);
+///
+/// assert_eq!(queue.try_pop_front(), Some(0));
+/// assert_eq!(queue.try_pop_front(), Some(1));
+/// assert_eq!(queue.try_pop_front(), None);
+/// assert_eq!(*queue.front(), 2);
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyVecDeque<T>(pub(crate) VecDeque<T>);
+
+impl<T> NonEmptyVecDeque<T> {
+    /// Creates a new `NonEmptyVecDeque`, with precisely one element inside of it.
+    #[inline]
+    pub fn new(item: T) -> NonEmptyVecDeque<T> {
+        NonEmptyVecDeque::with_capacity(item, 1)
+    }
+
+    /// Creates a new `NonEmptyVecDeque`, with precisely one element inside of it, and a
+    /// stated capacity (unless `capacity` is 0, in which case the actual capacity will be 1).
+    #[inline]
+    pub fn with_capacity(item: T, capacity: usize) -> NonEmptyVecDeque<T> {
+        let mut deque = VecDeque::with_capacity(capacity);
+        deque.push_back(item);
+        NonEmptyVecDeque(deque)
+    }
+
+    /// Safely turns a `VecDeque` into a `NonEmptyVecDeque` if the queue is not empty,
+    /// otherwise an `EmptyError` containing the original queue is returned.
+    #[inline]
+    pub fn try_from_vec_deque(deque: VecDeque<T>) -> Result<NonEmptyVecDeque<T>, EmptyError<VecDeque<T>>> {
+        if deque.is_empty() { Err(EmptyError(deque)) }
+        else { Ok(NonEmptyVecDeque(deque)) }
+    }
+
+    /// # Safety
+    /// `deque` must not be empty.
+    #[inline]
+    pub unsafe fn from_vec_deque_unchecked(deque: VecDeque<T>) -> NonEmptyVecDeque<T> {
+        NonEmptyVecDeque(deque)
+    }
+
+    /// Moves the inner queue out of the `NonEmptyVecDeque`.
+    #[inline]
+    pub fn into_vec_deque(self) -> VecDeque<T> {
+        self.0
+    }
+
+    /// Gives a read-only reference to the inner queue.
+    #[inline]
+    pub const fn get_vec_deque(&self) -> &VecDeque<T> {
+        &self.0
+    }
+
+    // Getting a mutable reference to the inner queue is not
+    // allowed since it may be modified to become empty
+
+    /// Returns the number of elements in the queue, guaranteeing that it won't be 0.
+    /// If you need the result to be a `usize`, use `get_len` instead.
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+
+    /// Returns the number of elements in the queue, as a `usize`.
+    /// If you need the result to be a `NonZeroUsize`, use `len` instead.
+    #[inline]
+    pub fn get_len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the queue has just 1 element. \
+    /// Used internaly to check if removing an item would cause the object to become empty.
+    #[inline]
+    pub fn has_just_1_element(&self) -> bool {
+        self.get_len() == 1
+    }
+
+    /// Returns the capacity of the queue, which is guaranteed not to be 0.
+    #[inline]
+    pub fn capacity(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.capacity()) }
+    }
+
+    /// Wrapper around `VecDeque::reserve`, reimplemented since a direct mutable reference cannot be given to the underlying queue.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional)
+    }
+
+    /// Wrapper around `VecDeque::reserve_exact`, reimplemented since a direct mutable reference cannot be given to the underlying queue.
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional)
+    }
+
+    /// Wrapper around `VecDeque::shrink_to_fit`, reimplemented since a direct mutable reference cannot be given to the underlying queue.
+    /// This only affects the queue's capacity, and as such is safe to use.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    /// Returns a reference to the front element of the queue.
+    /// The queue is guaranteed to have at least 1 item, so this method is infallible.
+    #[inline]
+    pub fn front(&self) -> &T {
+        unsafe { self.0.front().unwrap_unchecked() }
+    }
+
+    /// Returns a mutable reference to the front element of the queue.
+    /// The queue is guaranteed to have at least 1 item, so this method is infallible.
+    #[inline]
+    pub fn front_mut(&mut self) -> &mut T {
+        unsafe { self.0.front_mut().unwrap_unchecked() }
+    }
+
+    /// Returns a reference to the back element of the queue.
+    /// The queue is guaranteed to have at least 1 item, so this method is infallible.
+    #[inline]
+    pub fn back(&self) -> &T {
+        unsafe { self.0.back().unwrap_unchecked() }
+    }
+
+    /// Returns a mutable reference to the back element of the queue.
+    /// The queue is guaranteed to have at least 1 item, so this method is infallible.
+    #[inline]
+    pub fn back_mut(&mut self) -> &mut T {
+        unsafe { self.0.back_mut().unwrap_unchecked() }
+    }
+
+    /// Wrapper around `VecDeque::get`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.0.get(index)
+    }
+
+    /// Wrapper around `VecDeque::get_mut`. Modifying an element cannot cause the queue to become empty.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.0.get_mut(index)
+    }
+
+    /// Wrapper around `VecDeque::swap`.
+    #[inline]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.0.swap(i, j)
+    }
+
+    /// Wrapper around `VecDeque::iter`.
+    #[inline]
+    pub fn iter(&self) -> alloc::collections::vec_deque::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Wrapper around `VecDeque::iter_mut`.
+    #[inline]
+    pub fn iter_mut(&mut self) -> alloc::collections::vec_deque::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    /// Returns an iterator over the queue, which is guaranteed to yield at least one item.
+    #[inline]
+    pub fn iter_ne(&self) -> NonEmptyIter<alloc::collections::vec_deque::Iter<'_, T>> {
+        NonEmptyIter(self.0.iter())
+    }
+
+    /// Creates a consuming iterator, which is guaranteed to yield at least one item.
+    #[inline]
+    pub fn into_iter_ne(self) -> NonEmptyIter<alloc::collections::vec_deque::IntoIter<T>> {
+        NonEmptyIter(self.0.into_iter())
+    }
+
+    /// Wrapper around `VecDeque::as_slices`. Only one of the slices is guaranteed not to be empty,
+    /// so they are returned as regular slices.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.0.as_slices()
+    }
+
+    /// Wrapper around `VecDeque::as_mut_slices`. Only one of the slices is guaranteed not to be empty,
+    /// so they are returned as regular slices.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.0.as_mut_slices()
+    }
+
+    /// Wrapper around `VecDeque::make_contiguous`, preserving non-emptyness guarantees.
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &mut NonEmptySlice<T> {
+        unsafe { NonEmptySlice::from_slice_unchecked_mut(self.0.make_contiguous()) }
+    }
+
+    /// Wrapper around `VecDeque::push_front`, reimplemented since a direct mutable reference cannot be given to the underlying queue.
+    #[inline]
+    pub fn push_front(&mut self, value: T) {
+        self.0.push_front(value)
+    }
+
+    /// Wrapper around `VecDeque::push_back`, reimplemented since a direct mutable reference cannot be given to the underlying queue.
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        self.0.push_back(value)
+    }
+
+    /// Safe wrapper around `VecDeque::pop_front`.\
+    /// Returns `None` and does not pop the element if this would cause the queue to become empty.
+    #[inline]
+    pub fn try_pop_front(&mut self) -> Option<T> {
+        if self.has_just_1_element() {
+            None
+        } else {
+            self.0.pop_front()
+        }
+    }
+
+    /// Safe wrapper around `VecDeque::pop_back`.\
+    /// Returns `None` and does not pop the element if this would cause the queue to become empty.
+    #[inline]
+    pub fn try_pop_back(&mut self) -> Option<T> {
+        if self.has_just_1_element() {
+            None
+        } else {
+            self.0.pop_back()
+        }
+    }
+
+    /// Wrapper around `VecDeque::insert`, reimplemented since a direct mutable reference cannot be given to the underlying queue.
+    #[inline]
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.0.insert(index, value)
+    }
+
+    /// Safe wrapper around `VecDeque::remove`.\
+    /// Returns `None` if this would cause the queue to become empty, or if `index` is out of bounds.
+    #[inline]
+    pub fn try_remove(&mut self, index: usize) -> Option<T> {
+        if self.has_just_1_element() {
+            None
+        } else {
+            self.0.remove(index)
+        }
+    }
+
+    /// Wrapper around `VecDeque::append`, reimplemented since a direct mutable reference cannot be given to the underlying queue.\
+    /// This method empties `other`, meaning it cannot be a `NonEmptyVecDeque`.
+    #[inline]
+    pub fn append_vec_deque(&mut self, other: &mut VecDeque<T>) {
+        self.0.append(other)
+    }
+
+    /// Wrapper around `VecDeque::truncate`. If `len` were 0, that would cause the queue to become empty.
+    #[inline]
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.0.truncate(len.get())
+    }
+
+    /// Wrapper around `VecDeque::rotate_left`.
+    #[inline]
+    pub fn rotate_left(&mut self, n: usize) {
+        self.0.rotate_left(n)
+    }
+
+    /// Wrapper around `VecDeque::rotate_right`.
+    #[inline]
+    pub fn rotate_right(&mut self, n: usize) {
+        self.0.rotate_right(n)
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for NonEmptyVecDeque<T> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl<T> core::ops::Index<usize> for NonEmptyVecDeque<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T> core::ops::IndexMut<usize> for NonEmptyVecDeque<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVecDeque<T> {
+    type Item = T;
+    type IntoIter = alloc::collections::vec_deque::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyVecDeque<T> {
+    type Item = &'a T;
+    type IntoIter = alloc::collections::vec_deque::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NonEmptyVecDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = alloc::collections::vec_deque::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T> Extend<T> for NonEmptyVecDeque<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> FromNonEmptyIterator<T> for NonEmptyVecDeque<T> {
+    #[inline]
+    fn from_non_empty_iter<I: NonEmptyIterator<Item = T>>(iter: I) -> NonEmptyVecDeque<T> {
+        NonEmptyVecDeque(iter.into_iter().collect())
+    }
+}
+
+
+
+impl<T> TryFrom<VecDeque<T>> for NonEmptyVecDeque<T> {
+    type Error = EmptyError<VecDeque<T>>;
+
+    #[inline]
+    fn try_from(deque: VecDeque<T>) -> Result<NonEmptyVecDeque<T>, EmptyError<VecDeque<T>>> {
+        NonEmptyVecDeque::try_from_vec_deque(deque)
+    }
+}
+
+impl<T> From<NonEmptyVecDeque<T>> for VecDeque<T> {
+    #[inline]
+    fn from(deque: NonEmptyVecDeque<T>) -> VecDeque<T> {
+        deque.0
+    }
+}
+
+/// Same as `VecDeque::from(Vec)`, this conversion does not reallocate.
+impl<T> From<NonEmptyVec<T>> for NonEmptyVecDeque<T> {
+    #[inline]
+    fn from(vec: NonEmptyVec<T>) -> NonEmptyVecDeque<T> {
+        NonEmptyVecDeque(vec.0.into())
+    }
+}
+
+/// Same as `Vec::from(VecDeque)`, this conversion does not reallocate, but may move elements around.
+impl<T> From<NonEmptyVecDeque<T>> for NonEmptyVec<T> {
+    #[inline]
+    fn from(deque: NonEmptyVecDeque<T>) -> NonEmptyVec<T> {
+        NonEmptyVec(deque.0.into())
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for NonEmptyVecDeque<T> {
+    /// The length of the array is checked at compile time, and as such this method is infalible.
+    /// If the length of the array is not 0, a compiler error will be given. This requires a full build and does not show up when running `cargo check`.
+    #[inline]
+    fn from(arr: [T; N]) -> NonEmptyVecDeque<T> {
+        const { assert!(N > 0, "Length of array must be non-zero to create NonEmptyVecDeque."); }
+        NonEmptyVecDeque(arr.into())
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use crate::non_empty_vec::*;
+
+/// A type with an associative operation to combine two values into one.
+///
+/// Unlike `core::iter::Sum`, this does not require an identity element,
+/// which is enough to combine the items of a non-empty collection, as done by `NonEmptySlice::combine_all`.\
+/// Numeric types are combined by adding them, collections by appending one to the other.
+///
+/// # Examples
+///
+/// ```
+/// # use non_empty_continuous::*;
+/// # #[cfg(feature = "alloc")] {
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Interval(i32, i32);
+///
+/// impl Semigroup for Interval {
+///     fn combine(self, other: Interval) -> Interval {
+///         Interval(self.0.min(other.0), self.1.max(other.1))
+///     }
+/// }
+///
+/// let intervals = ne_vec![Interval(3, 5), Interval(1, 2), Interval(4, 8)];
+/// assert_eq!(intervals.combine_all(), Interval(1, 8));
+/// # }
+/// ```
+pub trait Semigroup {
+    /// Combines two values into one. This operation must be associative,
+    /// meaning `a.combine(b).combine(c)` is the same as `a.combine(b.combine(c))`.
+    fn combine(self, other: Self) -> Self;
+}
+
+macro_rules! impl_semigroup_add {
+    ($($t:ty)*) => {
+        $(
+            impl Semigroup for $t {
+                #[inline]
+                fn combine(self, other: $t) -> $t {
+                    self + other
+                }
+            }
+        )*
+    };
+}
+
+impl_semigroup_add!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+impl Semigroup for () {
+    #[inline]
+    fn combine(self, _other: ()) {}
+}
+
+/// `None` is ignored, so that only the `Some` values are combined.
+impl<T: Semigroup> Semigroup for Option<T> {
+    #[inline]
+    fn combine(self, other: Option<T>) -> Option<T> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+}
+
+macro_rules! impl_semigroup_tuple {
+    ($($t:ident $a:ident $b:ident),*) => {
+        impl<$($t: Semigroup),*> Semigroup for ($($t,)*) {
+            #[inline]
+            fn combine(self, other: ($($t,)*)) -> ($($t,)*) {
+                let ($($a,)*) = self;
+                let ($($b,)*) = other;
+                ($($a.combine($b),)*)
+            }
+        }
+    };
+}
+
+impl_semigroup_tuple!(A a1 a2);
+impl_semigroup_tuple!(A a1 a2, B b1 b2);
+impl_semigroup_tuple!(A a1 a2, B b1 b2, C c1 c2);
+impl_semigroup_tuple!(A a1 a2, B b1 b2, C c1 c2, D d1 d2);
+impl_semigroup_tuple!(A a1 a2, B b1 b2, C c1 c2, D d1 d2, E e1 e2);
+impl_semigroup_tuple!(A a1 a2, B b1 b2, C c1 c2, D d1 d2, E e1 e2, F f1 f2);
+
+#[cfg(feature = "alloc")]
+impl Semigroup for String {
+    #[inline]
+    fn combine(mut self, other: String) -> String {
+        self.push_str(&other);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Semigroup for NonEmptyVec<T> {
+    #[inline]
+    fn combine(mut self, other: NonEmptyVec<T>) -> NonEmptyVec<T> {
+        self.0.extend(other.0);
+        self
+    }
+}
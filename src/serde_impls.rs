@@ -0,0 +1,117 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use serde::de::{Deserialize, Deserializer, Error, Expected};
+use serde::ser::{Serialize, Serializer};
+
+use crate::non_empty_slice::*;
+use crate::non_empty_str::*;
+use crate::non_empty_string::*;
+use crate::non_empty_vec::*;
+use crate::non_empty_vec_deque::*;
+
+/// Error message given when deserializing an empty sequence into a non-empty type.
+struct AtLeastOneElement(&'static str);
+
+impl Expected for AtLeastOneElement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "at least one element in {}", self.0)
+    }
+}
+
+// Serializing `NonEmptySlice` also covers `&NonEmptySlice` and `Box<NonEmptySlice>`.
+impl<T: Serialize> Serialize for NonEmptySlice<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<T: Serialize> Serialize for NonEmptyVec<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Fails with an `invalid_length` error if the sequence is empty.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonEmptyVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        NonEmptyVec::try_from_vec(vec)
+            .map_err(|_| D::Error::invalid_length(0, &AtLeastOneElement("NonEmptyVec")))
+    }
+}
+
+/// Fails with an `invalid_length` error if the sequence is empty.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Box<NonEmptySlice<T>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        NonEmptyVec::try_from_vec(vec)
+            .map(NonEmptyVec::into_boxed_slice)
+            .map_err(|_| D::Error::invalid_length(0, &AtLeastOneElement("Box<NonEmptySlice>")))
+    }
+}
+
+impl<T: Serialize> Serialize for NonEmptyVecDeque<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Fails with an `invalid_length` error if the sequence is empty.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonEmptyVecDeque<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let deque = alloc::collections::VecDeque::<T>::deserialize(deserializer)?;
+        NonEmptyVecDeque::try_from_vec_deque(deque)
+            .map_err(|_| D::Error::invalid_length(0, &AtLeastOneElement("NonEmptyVecDeque")))
+    }
+}
+
+impl Serialize for NonEmptyStr {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl Serialize for NonEmptyString {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Fails with an `invalid_length` error if the string is empty.
+impl<'de> Deserialize<'de> for NonEmptyString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        NonEmptyString::try_from_string(string)
+            .map_err(|_| D::Error::invalid_length(0, &AtLeastOneElement("NonEmptyString")))
+    }
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec_impls {
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+    use smallvec::{Array, SmallVec};
+
+    use crate::non_empty_smallvec::*;
+    use super::AtLeastOneElement;
+
+    impl<A: Array> Serialize for NonEmptySmallVec<A> where A::Item: Serialize {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    /// Fails with an `invalid_length` error if the sequence is empty.
+    impl<'de, A: Array> Deserialize<'de> for NonEmptySmallVec<A> where A::Item: Deserialize<'de> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let smallvec = SmallVec::<A>::deserialize(deserializer)?;
+            NonEmptySmallVec::try_from_smallvec(smallvec)
+                .map_err(|_| D::Error::invalid_length(0, &AtLeastOneElement("NonEmptySmallVec")))
+        }
+    }
+}
@@ -0,0 +1,218 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::{Float, NumCast, Zero};
+#[cfg(feature = "alloc")]
+use num_traits::One;
+
+use crate::non_empty_slice::*;
+
+/// A number whose non-empty slices can be summarized with statistics, such as `NonEmptySlice::mean`.
+///
+/// This is implemented for all primitive floats and integers.
+/// Statistics that aren't necessarily one of the elements, like the mean or the variance, are given as `Self::Mean`,
+/// which is `f64` for integers and the float type itself for floats.
+pub trait Numeric: Copy + PartialOrd {
+    /// The float type used for statistics that aren't one of the elements.
+    type Mean: Float;
+
+    /// Converts the number into `Self::Mean`.
+    fn to_mean(self) -> Self::Mean;
+
+    /// Whether the number is NaN, which is always false for integers.
+    #[inline]
+    fn is_nan(self) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_numeric_float {
+    ($($t:ty)*) => {
+        $(
+            impl Numeric for $t {
+                type Mean = $t;
+
+                #[inline]
+                fn to_mean(self) -> $t {
+                    self
+                }
+
+                #[inline]
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_numeric_int {
+    ($($t:ty)*) => {
+        $(
+            impl Numeric for $t {
+                type Mean = f64;
+
+                #[inline]
+                fn to_mean(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_float!(f32 f64);
+impl_numeric_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// Statistics on numbers. Since the slice is never empty, these all return plain values instead of `Option`s.
+///
+/// If any element is NaN, every one of these methods returns NaN,
+/// since NaN cannot be ordered nor meaningfully added to other numbers.
+///
+/// # Examples
+///
+/// ```
+/// # use non_empty_continuous::*;
+/// # #[cfg(feature = "alloc")] {
+/// let non_empty_vec = ne_vec![4.0, 1.0, 3.0, 2.0];
+/// assert_eq!(non_empty_vec.mean(), 2.5);
+/// assert_eq!(non_empty_vec.median(), 2.0);
+/// assert_eq!(non_empty_vec.median_interpolated(), 2.5);
+/// assert_eq!(non_empty_vec.variance(), 1.25);
+/// assert_eq!(non_empty_vec.range(), 3.0);
+///
+/// let integers = ne_vec![2, 4, 4, 4, 5, 5, 7, 9];
+/// assert_eq!((integers.mean(), integers.std_dev()), (5.0, 2.0));
+/// assert_eq!((integers.percentile(0.0), integers.percentile(75.0), integers.percentile(100.0)), (2, 5, 9));
+///
+/// let with_nan = ne_vec![1.0, f64::NAN, 3.0];
+/// assert!(with_nan.mean().is_nan() && with_nan.range().is_nan());
+/// assert!(with_nan.median().is_nan() && with_nan.percentile(0.0).is_nan());
+/// # }
+/// ```
+impl<T: Numeric> NonEmptySlice<T> {
+    /// Returns the arithmetic mean of the elements.
+    #[inline]
+    pub fn mean(&self) -> T::Mean {
+        self.sum_mean() / self.len_mean()
+    }
+
+    /// Returns the population variance of the elements, which is the mean of the squared differences from the mean.
+    #[inline]
+    pub fn variance(&self) -> T::Mean {
+        let mean = self.mean();
+        let squares = self.0.iter().fold(T::Mean::zero(), |acc, &item| {
+            let difference: T::Mean = item.to_mean() - mean;
+            acc + difference * difference
+        });
+        squares / self.len_mean()
+    }
+
+    /// Returns the population standard deviation of the elements, which is the square root of `variance`.
+    #[inline]
+    pub fn std_dev(&self) -> T::Mean {
+        self.variance().sqrt()
+    }
+
+    /// Returns the difference between the largest and the smallest element.\
+    /// This is given as `T::Mean` so that it cannot overflow, since the difference between two integers may not fit in their type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// # #[cfg(feature = "alloc")] {
+    /// assert_eq!(ne_vec![i8::MIN, i8::MAX].range(), 255.0);
+    /// assert_eq!(ne_vec![u64::MAX, 0].range(), u64::MAX as f64);
+    /// # }
+    /// ```
+    pub fn range(&self) -> T::Mean {
+        let (&first, rest) = self.split_first();
+        let mut min_max = (first, first);
+        for &item in rest {
+            if item.is_nan() {
+                return T::Mean::nan();
+            }
+            if item < min_max.0 {
+                min_max.0 = item;
+            } else if item > min_max.1 {
+                min_max.1 = item;
+            }
+        }
+        min_max.1.to_mean() - min_max.0.to_mean()
+    }
+
+    /// Returns the median of the elements using the nearest-rank method, which is always one of the elements.\
+    /// For an even number of elements, this is the lower of the two middle ones.
+    /// Use `median_interpolated` to average them instead.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn median(&self) -> T {
+        self.percentile(50.0)
+    }
+
+    /// Returns the median of the elements. For an even number of elements, this is the mean of the two middle ones.
+    #[cfg(feature = "alloc")]
+    pub fn median_interpolated(&self) -> T::Mean {
+        let Some(mut items) = self.to_comparable_vec() else { return T::Mean::nan() };
+        let middle = items.len() / 2;
+        let (lower, &mut upper, _) = items.select_nth_unstable_by(middle, compare);
+        match lower.iter().copied().max_by(compare) {
+            // `lower` is only empty for a single element.
+            Some(lower) if self.0.len().is_multiple_of(2) => (lower.to_mean() + upper.to_mean()) / (T::Mean::one() + T::Mean::one()),
+            _ => upper.to_mean(),
+        }
+    }
+
+    /// Returns the `p`-th percentile of the elements using the nearest-rank method, which is always one of the elements.\
+    /// `percentile(0.0)` is the smallest element, and `percentile(100.0)` the largest.
+    ///
+    /// # Panics
+    /// Panics if `p` is not between 0 and 100, or is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use non_empty_continuous::*;
+    /// let non_empty_vec: NonEmptyVec<i32> = NonEmptyVec::try_from_iter(1..=100).unwrap();
+    /// assert_eq!(non_empty_vec.percentile(7.0), 7);
+    /// assert_eq!(non_empty_vec.percentile(14.0), 14);
+    /// assert_eq!(non_empty_vec.percentile(0.5), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn percentile(&self, p: f64) -> T {
+        assert!((0.0..=100.0).contains(&p), "percentile (is {p}) should be between 0 and 100");
+        let Some(mut items) = self.to_comparable_vec() else { return *self.0.iter().find(|item| item.is_nan()).unwrap() };
+        // Dividing last keeps exact ranks exact, since `p / 100.0` is often not representable.
+        let rank = Float::ceil(p * self.0.len() as f64 / 100.0) as usize;
+        *items.select_nth_unstable_by(rank.max(1) - 1, compare).1
+    }
+
+    #[inline]
+    fn sum_mean(&self) -> T::Mean {
+        self.0.iter().fold(T::Mean::zero(), |acc, &item| acc + item.to_mean())
+    }
+
+    #[inline]
+    fn len_mean(&self) -> T::Mean {
+        <T::Mean as NumCast>::from(self.0.len()).unwrap_or_else(T::Mean::infinity)
+    }
+
+    // Copies the elements so that they can be reordered, or returns `None` if any of them is NaN.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn to_comparable_vec(&self) -> Option<Vec<T>> {
+        if self.0.iter().any(|item| item.is_nan()) {
+            None
+        } else {
+            Some(self.0.to_vec())
+        }
+    }
+}
+
+// Only called once NaN has been ruled out, making the order total.
+#[cfg(feature = "alloc")]
+#[inline]
+fn compare<T: PartialOrd>(a: &T, b: &T) -> core::cmp::Ordering {
+    a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal)
+}
@@ -0,0 +1,407 @@
+// Methods shared by `NonEmptyVec<T>` and `allocator_api::NonEmptyVec<T, A>`, whose inner vectors have the same API.
+// `$vec` is the type of the inner vector, `$drain` the type returned by its `drain`,
+// and `$reserve_error` the error returned by its `try_reserve`.
+macro_rules! vec_wrapper_methods {
+    (vec: $vec:ty, drain: $drain:ty, reserve_error: $reserve_error:ty) => {
+        /// Returns the capacity of the vector, which is guaranteed not to be 0.
+        #[inline]
+        pub fn capacity(&self) -> NonZeroUsize {
+            unsafe { NonZeroUsize::new_unchecked(self.0.capacity()) }
+        }
+
+        // `additional` does not need to be non-zero (goes for all subcequent methods)
+
+        /// Wrapper around `Vec::reserve`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+        #[inline]
+        pub fn reserve(&mut self, additional: usize) {
+            self.0.reserve(additional)
+        }
+
+        /// Wrapper around `Vec::reserve_exact`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+        #[inline]
+        pub fn reserve_exact(&mut self, additional: usize) {
+            self.0.reserve_exact(additional)
+        }
+
+        /// Wrapper around `Vec::try_reserve`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+        #[inline]
+        pub fn try_reserve(&mut self, additional: usize) -> Result<(), $reserve_error> {
+            self.0.try_reserve(additional)
+        }
+
+        /// Wrapper around `Vec::try_reserve_exact`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+        #[inline]
+        pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), $reserve_error> {
+            self.0.try_reserve_exact(additional)
+        }
+
+        /// Wrapper around `Vec::shrink_to_fit`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+        /// This only affects the vector's capacity, and as such is safe to use.
+        #[inline]
+        pub fn shrink_to_fit(&mut self) {
+            self.0.shrink_to_fit()
+        }
+
+        /// Wrapper around `Vec::shrink_to`, reimplemented since a direct mutable reference cannot be given to the underlying vector.
+        /// This only affects the vector's capacity, and as such is safe to use.
+        #[inline]
+        pub fn shrink_to(&mut self, min_capacity: usize) {
+            self.0.shrink_to(min_capacity)
+        }
+
+        /// Wrapper around `Vec::truncate`. If `len` were 0, that would cause the vector to become empty.
+        #[inline]
+        pub fn truncate(&mut self, len: NonZeroUsize) {
+            self.0.truncate(len.get())
+        }
+
+        /// Gets the underlying slice pointed to by the vector.
+        /// This type implements `Deref<Target = NonEmptySlice<T>`, consider simply borrowing the value.
+        #[inline]
+        pub fn as_slice(&self) -> &NonEmptySlice<T> {
+            self
+        }
+
+        /// Gets the underlying slice pointed to by the vector.
+        /// This type implements `DerefMut<Target = NonEmptySlice<T>`, consider simply borrowing the value.
+        #[inline]
+        pub fn as_slice_mut(&mut self) -> &mut NonEmptySlice<T> {
+            self
+        }
+
+        /// Wrapper around `Vec::set_len`.
+        /// # Safety
+        /// This comes with the same requirements as `Vec::set_len`.
+        #[inline]
+        pub unsafe fn set_len(&mut self, new_len: NonZeroUsize) {
+            self.0.set_len(new_len.get())
+        }
+
+        // `Vec::retain` cannot be implemented directly since the function may retain no items.
+        // The variants below call `f` exactly once per element, in order.
+
+        /// Safe wrapper around `Vec::retain`.\
+        /// Returns `false` and does not modify the vector if `f` would retain no elements.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use non_empty_continuous::*;
+        /// let mut non_empty_vec = ne_vec![1, 2, 3, 4];
+        /// assert!(non_empty_vec.try_retain(|x| x % 2 == 0));
+        /// assert_eq!(non_empty_vec, ne_vec![2, 4]);
+        ///
+        /// assert!(!non_empty_vec.try_retain(|x| *x > 10));
+        /// assert_eq!(non_empty_vec, ne_vec![2, 4]);
+        /// ```
+        #[inline]
+        pub fn try_retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> bool {
+            self.try_retain_mut(|item| f(item))
+        }
+
+        /// Safe wrapper around `Vec::retain_mut`.\
+        /// Returns `false` and does not remove any elements if `f` would retain no elements.
+        /// Elements may still have been modified by `f`.
+        #[inline]
+        pub fn try_retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> bool {
+            crate::vec_wrapper::try_retain_mut(&mut self.0, f)
+        }
+
+        /// Same as `Vec::retain`, but keeps the first element if `f` would retain no elements.\
+        /// Returns `false` if that is the case.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use non_empty_continuous::*;
+        /// let mut non_empty_vec = ne_vec![1, 2, 3];
+        /// assert!(!non_empty_vec.retain_or_first(|x| *x > 10));
+        /// assert_eq!(non_empty_vec, ne_vec![1]);
+        /// ```
+        #[inline]
+        pub fn retain_or_first<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> bool {
+            self.retain_mut_or_first(|item| f(item))
+        }
+
+        /// Same as `Vec::retain_mut`, but keeps the first element if `f` would retain no elements.\
+        /// Returns `false` if that is the case.
+        #[inline]
+        pub fn retain_mut_or_first<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> bool {
+            let retained = self.try_retain_mut(f);
+            if !retained {
+                self.0.truncate(1);
+            }
+            retained
+        }
+
+        /// Same as `Vec::retain`, but keeps the last element if `f` would retain no elements.\
+        /// Returns `false` if that is the case.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use non_empty_continuous::*;
+        /// let mut non_empty_vec = ne_vec![1, 2, 3];
+        /// assert!(!non_empty_vec.retain_or_last(|x| *x > 10));
+        /// assert_eq!(non_empty_vec, ne_vec![3]);
+        /// ```
+        #[inline]
+        pub fn retain_or_last<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> bool {
+            self.retain_mut_or_last(|item| f(item))
+        }
+
+        /// Same as `Vec::retain_mut`, but keeps the last element if `f` would retain no elements.\
+        /// Returns `false` if that is the case.
+        #[inline]
+        pub fn retain_mut_or_last<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> bool {
+            let retained = self.try_retain_mut(f);
+            if !retained {
+                self.0.drain(..self.get_len() - 1);
+            }
+            retained
+        }
+
+        /// Wrapper around `Vec::dedup_by_key`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
+        /// Dedup cannot leave the vector empty so this method is safe to use.
+        #[inline]
+        pub fn dedup_by_key<F, K>(&mut self, key: F) where F: FnMut(&mut T) -> K, K: PartialEq {
+            self.0.dedup_by_key(key)
+        }
+
+        /// Wrapper around `Vec::dedup_by`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
+        /// Dedup cannot leave the vector empty so this method is safe to use.
+        #[inline]
+        pub fn dedup_by<F>(&mut self, same_bucket: F) where F: FnMut(&mut T, &mut T) -> bool {
+            self.0.dedup_by(same_bucket)
+        }
+
+        /// Wrapper around `Vec::push`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
+        /// Pushes an element to the end of the vector, reallocatig if needed.
+        #[inline]
+        pub fn push(&mut self, value: T) {
+            self.0.push(value)
+        }
+
+        /// Wrapper around `Vec::insert`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
+        /// Inserts the element at the given index, shifting items as needed.
+        #[inline]
+        pub fn insert(&mut self, index: usize, element: T) {
+            self.0.insert(index, element)
+        }
+
+        /// Wrapper around `Vec::append`, reimplemented since a direct mutable reference cannot be given to the underlying vector.\
+        /// This method empties `other`, meaning it cannot be a `NonEmptyVec`.
+        #[inline]
+        pub fn append_vec(&mut self, other: &mut $vec) {
+            self.0.append(other)
+        }
+
+        /// Safe wrapper around `Vec::pop`.\
+        /// Returns `None` and does not pop the element if this would cause the vector to become empty.
+        #[inline]
+        pub fn try_pop(&mut self) -> Option<T> {
+            if self.has_just_1_element() {
+                None
+            } else {
+                self.0.pop()
+            }
+        }
+
+        /// Wrapper around `Vec::swap_remove`.\
+        /// This method ensures that it won't cause the vector to become empty by not allowing the first element to be removed.
+        /// For a mehod that accepts any index, use `NonEmptyVec::try_swap_remove`.
+        #[inline]
+        pub fn swap_remove(&mut self, index: NonZeroUsize) -> T {
+            self.0.swap_remove(index.get())
+        }
+
+        /// Safe wrapper around `Vec::swap_remove`.\
+        /// Returns `None` if this would cause the vector to become empty.
+        /// Otherwise, moves out the element at `index` and replaces it with the last element in the vector.
+        #[inline]
+        pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+            if self.has_just_1_element() {
+                None
+            } else {
+                Some(self.0.swap_remove(index))
+            }
+        }
+
+        /// Unsafe wrapper around `Vec::swap_remove`.\
+        /// For a safe version of this method, use `NonEmptyVec::try_swap_remove`.
+        /// # Safety
+        /// Running this must not cause the vector to become empty.
+        #[inline]
+        pub unsafe fn swap_remove_unchecked(&mut self, index: usize) -> T {
+            self.0.swap_remove(index)
+        }
+
+        /// Safe wrapper around `Vec::remove`.\
+        /// Returns `None` if this would cause the vector to become empty.
+        #[inline]
+        pub fn try_remove(&mut self, index: usize) -> Option<T> {
+            if self.has_just_1_element() {
+                None
+            } else {
+                Some(self.0.remove(index))
+            }
+        }
+
+        /// Unsafe wrapper around `Vec::remove`.\
+        /// For a safe version of this method, use `NonEmptyVec::try_remove`.
+        /// # Safety
+        /// Running this must not cause the vector to become empty.
+        #[inline]
+        pub unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+            self.0.remove(index)
+        }
+
+        /// Safe counterpart of `Vec::drain`.\
+        /// This method returns `None` and does not remove any elements if `range` covers the whole vector.
+        ///
+        /// Unlike `Vec::drain`, the elements are removed eagerly, into a vector using the global allocator.
+        /// A lazy `Drain` could be leaked, and `Vec::drain` leaves the vector empty until it is dropped.
+        #[inline]
+        pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Option<alloc::vec::IntoIter<T>> {
+            if range.contains(&0) && range.contains(&(self.get_len() - 1)) {
+                None
+            } else {
+                Some(self.0.drain(range).collect::<alloc::vec::Vec<T>>().into_iter())
+            }
+        }
+
+        /// Unsafe wrapper around `Vec::drain.`\
+        /// For a safe version of this method, use `NonEmptyVec::drain`.
+        /// # Safety
+        /// `range` must not take up the entire vector.
+        /// The returned `Drain` must not be leaked, since the vector is left empty until it is dropped.
+        #[inline]
+        pub unsafe fn drain_unchecked<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> $drain {
+            self.0.drain(range)
+        }
+
+        /// Safe wrapper around `Vec::resize_with`, that ensures the vector cannot become empty.
+        #[inline]
+        pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: NonZeroUsize, f: F) {
+            self.0.resize_with(new_len.get(), f)
+        }
+    };
+}
+
+// The inner vectors whose elements can be retained in place by `try_retain_mut`.
+pub(crate) trait InnerVec<T> {
+    fn as_mut_ptr(&mut self) -> *mut T;
+    fn len(&self) -> usize;
+    unsafe fn set_len(&mut self, new_len: usize);
+}
+
+impl<T> InnerVec<T> for alloc::vec::Vec<T> {
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.as_mut_ptr()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, new_len: usize) {
+        self.set_len(new_len)
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> InnerVec<A::Item> for smallvec::SmallVec<A> {
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut A::Item {
+        self.as_mut_ptr()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, new_len: usize) {
+        self.set_len(new_len)
+    }
+}
+
+#[cfg(feature = "allocator-api2")]
+impl<T, A: allocator_api2::alloc::Allocator> InnerVec<T> for allocator_api2::vec::Vec<T, A> {
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.as_mut_ptr()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, new_len: usize) {
+        self.set_len(new_len)
+    }
+}
+
+/// Retains the elements for which `f` returns `true` in a single pass, calling `f` exactly once per element, in order.\
+/// Returns `false` and leaves every element in place if `f` would retain no elements.
+///
+/// Rejected elements are only dropped once an element has been retained, so that they can be kept otherwise.
+/// If `f` or a destructor panics, the vector keeps every element that has not been dropped, so it cannot become empty.
+pub(crate) fn try_retain_mut<T, V: InnerVec<T>, F: FnMut(&mut T) -> bool>(vec: &mut V, mut f: F) -> bool {
+    // Elements in `0..processed - deleted` are final, and those in `processed..original_len` have not been visited yet.
+    struct Guard<'a, T, V: InnerVec<T>> {
+        vec: &'a mut V,
+        processed: usize,
+        deleted: usize,
+        original_len: usize,
+        _marker: core::marker::PhantomData<T>,
+    }
+
+    impl<T, V: InnerVec<T>> Drop for Guard<'_, T, V> {
+        fn drop(&mut self) {
+            unsafe {
+                if self.deleted > 0 {
+                    let ptr = self.vec.as_mut_ptr();
+                    core::ptr::copy(ptr.add(self.processed), ptr.add(self.processed - self.deleted), self.original_len - self.processed);
+                }
+                self.vec.set_len(self.original_len - self.deleted);
+            }
+        }
+    }
+
+    let original_len = vec.len();
+    // Avoids double drops if the guard is not run, which only happens when aborting.
+    unsafe { vec.set_len(0) };
+    let mut guard = Guard { vec, processed: 0, deleted: 0, original_len, _marker: core::marker::PhantomData };
+    let ptr = guard.vec.as_mut_ptr();
+    let mut retained_any = false;
+
+    for index in 0..original_len {
+        let item = unsafe { &mut *ptr.add(index) };
+        if f(item) {
+            if !retained_any {
+                retained_any = true;
+                // The elements before the first retained one are removed now that the vector cannot become empty.
+                guard.deleted = index;
+                guard.processed = index;
+                unsafe { core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(ptr, index)) };
+            }
+            if guard.deleted > 0 {
+                unsafe { core::ptr::copy_nonoverlapping(ptr.add(index), ptr.add(index - guard.deleted), 1) };
+            }
+        } else if retained_any {
+            guard.processed += 1;
+            guard.deleted += 1;
+            unsafe { core::ptr::drop_in_place(ptr.add(index)) };
+            continue;
+        }
+        guard.processed = index + 1;
+    }
+    retained_any
+}
@@ -0,0 +1,355 @@
+//! Regression tests for every method that hands out raw access to the inner storage,
+//! or that is implemented with `unsafe` code internally.
+//!
+//! These are meant to be run under Miri as well, which checks for undefined behaviour, double drops and leaks:
+//! `cargo +nightly miri test --all-features --test raw_access`
+
+#![cfg(feature = "alloc")]
+
+use core::num::NonZeroUsize;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use non_empty_continuous::*;
+
+fn nz(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new(n).unwrap()
+}
+
+// Heap-allocated elements, so that Miri notices double drops and leaks.
+fn strings(items: &[&str]) -> NonEmptyVec<String> {
+    NonEmptyVec::try_from_iter(items.iter().map(|s| s.to_string())).unwrap()
+}
+
+#[test]
+fn splice_rejects_emptying_the_vector() {
+    let mut vec = strings(&["a", "b", "c"]);
+    assert!(vec.splice(.., []).is_none());
+    assert!(vec.splice(0..3, Vec::new()).is_none());
+    assert!(vec.splice(0..=2, core::iter::empty()).is_none());
+    assert_eq!(vec, strings(&["a", "b", "c"]));
+}
+
+#[test]
+fn splice_replaces_elements() {
+    let mut vec = strings(&["a", "b", "c"]);
+
+    let removed: Vec<String> = vec.splice(.., [String::from("x")]).unwrap().collect();
+    assert_eq!(removed, ["a", "b", "c"]);
+    assert_eq!(vec, strings(&["x"]));
+
+    let removed: Vec<String> = vec.splice(1.., strings(&["y", "z"])).unwrap().collect();
+    assert!(removed.is_empty());
+    assert_eq!(vec, strings(&["x", "y", "z"]));
+
+    // A partial range may be replaced with nothing, since the rest of the vector remains.
+    let removed: Vec<String> = vec.splice(..2, []).unwrap().collect();
+    assert_eq!(removed, ["x", "y"]);
+    assert_eq!(vec, strings(&["z"]));
+}
+
+#[test]
+fn splice_dropped_without_iterating() {
+    let mut vec = strings(&["a", "b", "c"]);
+    drop(vec.splice(..2, strings(&["d", "e", "f"])));
+    assert_eq!(vec, strings(&["d", "e", "f", "c"]));
+
+    drop(unsafe { vec.splice_unchecked(.., strings(&["g"])) });
+    assert_eq!(vec, strings(&["g"]));
+}
+
+// Unit elements, so that leaking the returned iterator does not leak any memory.
+#[test]
+fn leaked_splice_keeps_the_vector_non_empty() {
+    let mut units = NonEmptyVec::from_arr([(), ()]);
+    core::mem::forget(units.splice(0..1, [()]).unwrap());
+    assert_eq!(units.len(), nz(2));
+
+    core::mem::forget(units.splice(.., [()]).unwrap());
+    assert_eq!(units.len(), nz(1));
+}
+
+#[test]
+fn spare_capacity_mut_may_be_empty() {
+    let mut vec = NonEmptyVec::from_arr([1, 2, 3]);
+    vec.shrink_to_fit();
+    assert!(vec.spare_capacity_mut().is_empty());
+}
+
+#[test]
+fn spare_capacity_mut_then_set_len() {
+    let mut vec = NonEmptyVec::with_capacity(String::from("a"), 4);
+    let spare = vec.spare_capacity_mut();
+    assert!(spare.len() >= 3);
+    spare[0].write(String::from("b"));
+    spare[1].write(String::from("c"));
+    unsafe { vec.set_len(nz(3)) };
+    assert_eq!(vec, strings(&["a", "b", "c"]));
+}
+
+#[test]
+fn from_raw_parts_round_trip() {
+    let mut vec = core::mem::ManuallyDrop::new(vec![String::from("a"), String::from("b")]);
+    let (ptr, len, capacity) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+    let vec = unsafe { NonEmptyVec::from_raw_parts(ptr, nz(len), nz(capacity)) };
+    assert_eq!(vec, strings(&["a", "b"]));
+}
+
+#[test]
+fn leak_and_reclaim() {
+    let mut vec = strings(&["a", "b"]);
+    // `Box::from_raw` needs the capacity to match the length.
+    vec.shrink_to_fit();
+    let leaked: &'static mut NonEmptySlice<String> = vec.leak();
+    leaked[0].push('!');
+    assert_eq!(leaked.first(), "a!");
+    let reclaimed = unsafe { Box::from_raw(leaked.get_slice_mut() as *mut [String]) };
+    assert_eq!(reclaimed.len(), 2);
+}
+
+#[test]
+fn unchecked_slice_casts() {
+    let mut array = [String::from("a"), String::from("b")];
+    let slice = unsafe { NonEmptySlice::from_slice_unchecked_mut(&mut array) };
+    slice[1].push('!');
+    let slice = unsafe { NonEmptySlice::from_slice_unchecked(&array) };
+    assert_eq!(slice.last(), "b!");
+}
+
+#[test]
+fn boxed_slice_conversions() {
+    let boxed: Box<NonEmptySlice<String>> = strings(&["a", "b"]).into_boxed_slice();
+    let cloned = boxed.clone();
+    let vec: NonEmptyVec<String> = boxed.into_vec();
+    assert_eq!(vec, strings(&["a", "b"]));
+
+    let plain: Box<[String]> = cloned.into();
+    assert_eq!(&*plain, ["a", "b"]);
+
+    let from_array: Box<NonEmptySlice<String>> = [String::from("c")].into();
+    assert_eq!(NonEmptyVec::from(from_array), strings(&["c"]));
+}
+
+#[test]
+fn rc_and_arc_conversions() {
+    let rc: Rc<NonEmptySlice<String>> = strings(&["a", "b"]).into();
+    let rc2 = Rc::clone(&rc);
+    assert_eq!(rc2.first(), "a");
+    drop(rc);
+    assert_eq!(Rc::strong_count(&rc2), 1);
+
+    let rc: Rc<NonEmptySlice<String>> = (&*strings(&["c"])).into();
+    assert_eq!(rc.last(), "c");
+
+    let arc: Arc<NonEmptySlice<String>> = strings(&["a", "b"]).into();
+    let arc2 = Arc::clone(&arc);
+    drop(arc);
+    assert_eq!(arc2.last(), "b");
+
+    let arc: Arc<NonEmptySlice<String>> = (&*strings(&["c"])).into();
+    assert_eq!(arc.first(), "c");
+}
+
+#[test]
+fn drain_and_unchecked_removals() {
+    let mut vec = strings(&["a", "b", "c", "d"]);
+    assert!(vec.drain(..).is_none());
+    let drained: Vec<String> = vec.drain(1..3).unwrap().collect();
+    assert_eq!(drained, ["b", "c"]);
+
+    // Dropping a drain without iterating still removes the range.
+    drop(unsafe { vec.drain_unchecked(..1) });
+    assert_eq!(vec, strings(&["d"]));
+
+    vec.extend(strings(&["e", "f"]));
+    assert_eq!(unsafe { vec.remove_unchecked(0) }, "d");
+    assert_eq!(unsafe { vec.swap_remove_unchecked(0) }, "e");
+    assert_eq!(vec, strings(&["f"]));
+}
+
+// Unit elements, so that leaking the returned iterator does not leak any memory.
+#[test]
+fn leaked_drain_keeps_the_vector_non_empty() {
+    let mut units = NonEmptyVec::from_arr([(), (), ()]);
+    core::mem::forget(units.drain(1..).unwrap());
+    assert_eq!(units.len(), nz(1));
+
+    #[cfg(feature = "smallvec")]
+    {
+        let mut units: NonEmptySmallVec<[(); 2]> = NonEmptySmallVec::from_buf([(), ()]);
+        core::mem::forget(units.drain(..1).unwrap());
+        assert_eq!(units.len(), nz(1));
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    {
+        let mut units: non_empty_continuous::allocator_api::NonEmptyVec<()> = non_empty_continuous::allocator_api::NonEmptyVec::from_arr([(), ()]);
+        core::mem::forget(units.drain(..1).unwrap());
+        assert_eq!(units.len(), nz(1));
+    }
+}
+
+#[test]
+fn edit_guards() {
+    let mut vec = strings(&["a", "b", "c"]);
+    vec.edit().retain(|s| s != "b");
+    assert_eq!(vec, strings(&["a", "c"]));
+
+    let mut guard = vec.edit();
+    guard.clear();
+    assert!(guard.commit().is_err());
+    assert_eq!(vec, strings(&["a", "c"]));
+
+    vec.edit_or(String::from("z")).clear();
+    assert_eq!(vec, strings(&["z"]));
+
+    // Leaking a guard leaves the vector as it was, or as `[fallback]`.
+    // Zero-sized elements are used so that nothing is allocated, since Miri would report the leak.
+    let mut units = NonEmptyVec::from_arr([(), ()]);
+    let mut guard = units.edit();
+    guard.clear();
+    core::mem::forget(guard);
+    assert_eq!(units.len(), nz(2));
+    core::mem::forget(units.edit_or(()));
+    assert_eq!(units.len(), nz(1));
+}
+
+#[test]
+fn extract_if_keeps_one_element() {
+    let mut vec = strings(&["a", "b", "c", "d"]);
+    let extracted: Vec<String> = vec.extract_if(1.., |s| s != "c").collect();
+    assert_eq!((extracted, &vec), (vec![String::from("b"), String::from("d")], &strings(&["a", "c"])));
+
+    let extracted: Vec<String> = vec.extract_if(.., |_| true).collect();
+    assert_eq!((extracted, &vec), (vec![String::from("a")], &strings(&["c"])));
+
+    vec.extend(strings(&["e", "f"]));
+    let extracted: Vec<String> = unsafe { vec.extract_if_unchecked(..2, |_| true) }.collect();
+    assert_eq!((extracted.len(), vec), (2, strings(&["f"])));
+}
+
+#[test]
+fn extract_if_panicking_filter() {
+    let mut vec = strings(&["a", "b", "c"]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.extract_if(.., |s| if s == "b" { panic!() } else { true }).count()
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec, strings(&["b", "c"]));
+}
+
+#[test]
+fn try_retain_mut_in_one_pass() {
+    let mut vec = strings(&["a", "b", "c", "d", "e"]);
+    let mut visited = Vec::new();
+    assert!(vec.try_retain_mut(|s| {
+        visited.push(s.clone());
+        s.push('!');
+        s == "b!" || s == "d!"
+    }));
+    assert_eq!(visited, ["a", "b", "c", "d", "e"]);
+    assert_eq!(vec, strings(&["b!", "d!"]));
+
+    assert!(!vec.try_retain_mut(|s| s.is_empty()));
+    assert_eq!(vec, strings(&["b!", "d!"]));
+}
+
+#[test]
+fn try_retain_mut_panicking_filter() {
+    let mut vec = strings(&["a", "b", "c", "d"]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.try_retain(|s| if s == "d" { panic!() } else { s == "b" })
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec, strings(&["b", "d"]));
+
+    // Nothing is removed before an element is retained.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.try_retain(|s| if s == "d" { panic!() } else { false })
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec, strings(&["b", "d"]));
+
+    #[cfg(feature = "smallvec")]
+    {
+        let mut inline: NonEmptySmallVec<[String; 4]> = NonEmptySmallVec::from_buf(["a", "b", "c", "d"].map(String::from));
+        assert!(inline.try_retain(|s| s != "a" && s != "c"));
+        assert_eq!(inline.as_slice().get_slice(), ["b", "d"]);
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    {
+        let mut vec: non_empty_continuous::allocator_api::NonEmptyVec<String> = strings(&["a", "b", "c"]).into();
+        assert!(!vec.try_retain(|s| s.is_empty()));
+        assert!(vec.try_retain(|s| s == "c"));
+        assert_eq!(vec.as_slice().get_slice(), ["c"]);
+    }
+}
+
+#[test]
+fn strings_and_bytes() {
+    let string = NonEmptyString::try_from("héllo").unwrap();
+    assert_eq!(string.as_bytes().len(), nz(6));
+
+    let bytes: NonEmptyVec<u8> = string.clone().into_bytes();
+    assert_eq!(NonEmptyString::from_utf8(bytes).unwrap(), string);
+
+    let s: &NonEmptyStr = unsafe { NonEmptyStr::from_str_unchecked("ok") };
+    let from_bytes: &NonEmptyStr = s.as_bytes().try_into().unwrap();
+    assert_eq!(from_bytes, "ok");
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec_raw_access() {
+    use smallvec::SmallVec;
+
+    let mut inline: NonEmptySmallVec<[String; 4]> = NonEmptySmallVec::new(String::from("a"));
+    inline.push(String::from("b"));
+    assert!(!inline.spilled());
+    inline.grow(nz(8));
+    assert!(inline.spilled());
+    assert_eq!(unsafe { inline.remove_unchecked(0) }, "a");
+    drop(unsafe { inline.drain_unchecked(1..) });
+    assert_eq!(inline.as_slice().get_slice(), ["b"]);
+
+    let mut spilled: SmallVec<[String; 1]> = SmallVec::new();
+    spilled.extend([String::from("c"), String::from("d")]);
+    let mut spilled = core::mem::ManuallyDrop::new(spilled.into_vec());
+    let (ptr, len, capacity) = (spilled.as_mut_ptr(), spilled.len(), spilled.capacity());
+    let mut from_raw: NonEmptySmallVec<[String; 1]> = unsafe { NonEmptySmallVec::from_raw_parts(ptr, nz(len), nz(capacity)) };
+    drop(from_raw.pop());
+    assert_eq!(from_raw.len(), nz(1));
+
+    let mut buf = core::mem::MaybeUninit::<[u8; 3]>::uninit();
+    unsafe { buf.as_mut_ptr().cast::<u8>().write(7) };
+    let from_buf = unsafe { NonEmptySmallVec::from_buf_and_len_unchecked(buf, nz(1)) };
+    assert_eq!(from_buf.as_slice().get_slice(), [7]);
+
+    let mut guarded: NonEmptySmallVec<[String; 2]> = NonEmptySmallVec::new(String::from("e"));
+    guarded.edit_or(String::from("f")).extend([String::from("g"), String::from("h")]);
+    assert_eq!(guarded.as_slice().get_slice(), ["e", "g", "h"]);
+    guarded.edit().clear();
+    assert_eq!(guarded.len(), nz(3));
+
+    let extracted: Vec<String> = guarded.extract_if(|_| true).collect();
+    assert_eq!((extracted.len(), guarded.len()), (2, nz(1)));
+    guarded.push(String::from("i"));
+    assert_eq!(unsafe { guarded.extract_if_unchecked(|s| s == "i") }.count(), 1);
+
+    let mut lengths: NonEmptySmallVec<[u8; 3]> = NonEmptySmallVec::from_buf_and_len([1, 2, 3], nz(1));
+    unsafe { lengths.set_len(nz(3)) };
+    assert_eq!(lengths.as_slice().get_slice(), [1, 2, 3]);
+}
+
+#[cfg(feature = "allocator-api2")]
+#[test]
+fn allocator_api_conversions() {
+    use non_empty_continuous::allocator_api;
+
+    let mut vec: allocator_api::NonEmptyVec<String> = strings(&["a", "b"]).into();
+    vec.push(String::from("c"));
+    assert!(vec.drain(..).is_none());
+    let root: NonEmptyVec<String> = vec.into();
+    assert_eq!(root, strings(&["a", "b", "c"]));
+}